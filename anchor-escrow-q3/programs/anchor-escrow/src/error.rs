@@ -0,0 +1,35 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum EscrowError {
+    #[msg("Order has expired")]
+    OrderExpired,
+    #[msg("Order does not match the provided accounts")]
+    OrderMismatch,
+    #[msg("Missing or malformed ed25519 signature instruction")]
+    InvalidSignatureInstruction,
+    #[msg("Order was not signed by the maker")]
+    InvalidSignature,
+    #[msg("Fee exceeds 1000 basis points")]
+    InvalidFee,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Invalid amount of tokens")]
+    InvalidAmount,
+    #[msg("Vesting schedule must satisfy start <= cliff <= end and start < end")]
+    InvalidSchedule,
+    #[msg("No vested tokens to claim")]
+    NothingToClaim,
+    #[msg("Vesting is not revocable")]
+    NotRevocable,
+    #[msg("Between 1 and 10 milestones are required")]
+    InvalidMilestones,
+    #[msg("Milestone index out of range")]
+    InvalidMilestoneIndex,
+    #[msg("Milestone is not in the required state")]
+    InvalidMilestoneStatus,
+    #[msg("Signer is not the program upgrade authority")]
+    NotUpgradeAuthority,
+    #[msg("Escrow terms do not match what the taker expected")]
+    TermsChanged,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MakeEvent {
+    pub maker: Pubkey,
+    pub escrow: Pubkey,
+    pub seed: u64,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub deposit: u64,
+    pub receive: u64,
+}
+
+#[event]
+pub struct TakeEvent {
+    pub taker: Pubkey,
+    pub maker: Pubkey,
+    pub escrow: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub deposit: u64,
+    pub receive: u64,
+}
+
+#[event]
+pub struct RefundEvent {
+    pub maker: Pubkey,
+    pub escrow: Pubkey,
+    pub mint_a: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct UpdateEvent {
+    pub maker: Pubkey,
+    pub escrow: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub receive: u64,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+    associated_token::AssociatedToken
+};
+
+use crate::error::EscrowError;
+use crate::state::{close_if_settled, MilestoneEscrow, MilestoneStatus};
+
+#[derive(Accounts)]
+pub struct CancelMilestone<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub arbiter: Signer<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+        associated_token::token_program = token_program,
+    )]
+    pub payer_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        has_one = payer,
+        has_one = arbiter,
+        has_one = mint,
+        seeds = [b"milestone", payer.key().as_ref(), milestone_escrow.seed.to_le_bytes().as_ref()],
+        bump = milestone_escrow.bump,
+    )]
+    pub milestone_escrow: Account<'info, MilestoneEscrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = milestone_escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelMilestone<'info> {
+    // With the arbiter's approval the payer reclaims a pending milestone, e.g. when the payee has gone
+    pub fn cancel(&mut self, index: u8) -> Result<()> {
+        let milestone = self.milestone_escrow.milestones
+            .get(index as usize)
+            .ok_or(EscrowError::InvalidMilestoneIndex)?
+            .clone();
+
+        require!(milestone.status == MilestoneStatus::Pending, EscrowError::InvalidMilestoneStatus);
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"milestone",
+            self.payer.to_account_info().key.as_ref(),
+            &self.milestone_escrow.seed.to_le_bytes()[..],
+            &[self.milestone_escrow.bump]
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.payer_ata.to_account_info(),
+            authority: self.milestone_escrow.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), transfer_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, milestone.amount, self.mint.decimals)?;
+
+        self.milestone_escrow.milestones[index as usize].status = MilestoneStatus::Cancelled;
+
+        Ok(())
+    }
+
+    pub fn close_if_settled(&mut self) -> Result<()> {
+        close_if_settled(
+            &mut self.milestone_escrow,
+            &mut self.vault,
+            &self.mint,
+            &self.payer.to_account_info(),
+            &self.payer_ata,
+            &self.token_program,
+        )
+    }
+}
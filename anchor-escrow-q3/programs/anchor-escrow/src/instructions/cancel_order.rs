@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::OrderNonce;
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct CancelOrder<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        init,
+        payer = maker,
+        seeds = [b"nonce", maker.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump,
+        space = OrderNonce::INIT_SPACE + 8,
+    )]
+    pub order_nonce: Account<'info, OrderNonce>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelOrder<'info> {
+    // Burns a single quote's nonce so it can no longer be taken, without revoking the delegate
+    pub fn cancel_order(&mut self, nonce: u64, bumps: &CancelOrderBumps) -> Result<()> {
+        self.order_nonce.set_inner(OrderNonce {
+            maker: self.maker.key(),
+            nonce,
+            bump: bumps.order_nonce,
+        });
+
+        Ok(())
+    }
+}
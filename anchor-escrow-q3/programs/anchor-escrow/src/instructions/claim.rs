@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, CloseAccount, transfer_checked, close_account},
+    associated_token::AssociatedToken
+};
+
+use crate::error::EscrowError;
+use crate::state::Vesting;
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    #[account(mut)]
+    pub maker: SystemAccount<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint_a,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = beneficiary,
+        has_one = mint_a,
+        seeds = [b"vesting", maker.key().as_ref(), vesting.seed.to_le_bytes().as_ref()],
+        bump = vesting.bump,
+    )]
+    pub vesting: Account<'info, Vesting>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Claim<'info> {
+    pub fn claim(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let vested = self.vesting.vested_amount(now).ok_or(EscrowError::ArithmeticOverflow)?;
+        let amount = vested.checked_sub(self.vesting.claimed).ok_or(EscrowError::ArithmeticOverflow)?;
+
+        require!(amount > 0, EscrowError::NothingToClaim);
+
+        // The final claim sweeps the vault so donations or transfer-fee shortfalls can't block the close
+        let transfer_amount = if vested == self.vesting.total {
+            self.vault.amount
+        } else {
+            amount.min(self.vault.amount)
+        };
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"vesting",
+            self.maker.to_account_info().key.as_ref(),
+            &self.vesting.seed.to_le_bytes()[..],
+            &[self.vesting.bump]
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.beneficiary_ata_a.to_account_info(),
+            authority: self.vesting.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), transfer_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, transfer_amount, self.mint_a.decimals)?;
+
+        self.vesting.claimed = vested;
+
+        Ok(())
+    }
+
+    // Once everything is claimed the vault and vesting account are closed back to the maker,
+    // so the close is never left for a later claim that would fail with `NothingToClaim`
+    pub fn close_if_complete(&mut self) -> Result<()> {
+        if self.vesting.claimed < self.vesting.total {
+            return Ok(());
+        }
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"vesting",
+            self.maker.to_account_info().key.as_ref(),
+            &self.vesting.seed.to_le_bytes()[..],
+            &[self.vesting.bump]
+        ]];
+
+        // Hand any remainder to the beneficiary so the vault is always empty before closing
+        self.vault.reload()?;
+        if self.vault.amount > 0 {
+            let transfer_accounts = TransferChecked {
+                from: self.vault.to_account_info(),
+                mint: self.mint_a.to_account_info(),
+                to: self.beneficiary_ata_a.to_account_info(),
+                authority: self.vesting.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), transfer_accounts, &signer_seeds);
+
+            transfer_checked(cpi_ctx, self.vault.amount, self.mint_a.decimals)?;
+        }
+
+        let close_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.maker.to_account_info(),
+            authority: self.vesting.to_account_info()
+        };
+
+        let close_cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), close_accounts, &signer_seeds);
+
+        close_account(close_cpi_ctx)?;
+
+        self.vesting.close(self.maker.to_account_info())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::error::EscrowError;
+use crate::state::{MilestoneEscrow, MilestoneStatus};
+
+#[derive(Accounts)]
+pub struct DisputeMilestone<'info> {
+    pub payee: Signer<'info>,
+    pub payer: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = payer,
+        has_one = payee,
+        seeds = [b"milestone", payer.key().as_ref(), milestone_escrow.seed.to_le_bytes().as_ref()],
+        bump = milestone_escrow.bump,
+    )]
+    pub milestone_escrow: Account<'info, MilestoneEscrow>,
+}
+
+impl<'info> DisputeMilestone<'info> {
+    pub fn dispute(&mut self, index: u8) -> Result<()> {
+        let milestone = self.milestone_escrow.milestones
+            .get_mut(index as usize)
+            .ok_or(EscrowError::InvalidMilestoneIndex)?;
+
+        require!(milestone.status == MilestoneStatus::Pending, EscrowError::InvalidMilestoneStatus);
+
+        milestone.status = MilestoneStatus::Disputed;
+
+        Ok(())
+    }
+}
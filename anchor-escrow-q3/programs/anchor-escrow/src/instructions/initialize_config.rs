@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::error::EscrowError;
+use crate::program::AnchorEscrow;
+use crate::state::EscrowConfig;
+
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub fee_recipient: SystemAccount<'info>,
+    // Only the program's upgrade authority may create the singleton config
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ EscrowError::NotUpgradeAuthority,
+    )]
+    pub program: Program<'info, AnchorEscrow>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ EscrowError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"config"],
+        bump,
+        space = EscrowConfig::INIT_SPACE + 8,
+    )]
+    pub config: Account<'info, EscrowConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeConfig<'info> {
+    pub fn init_config(&mut self, fee: u16, bumps: &InitializeConfigBumps) -> Result<()> {
+        require!(fee <= MAX_FEE_BPS, EscrowError::InvalidFee);
+
+        self.config.set_inner(EscrowConfig {
+            admin: self.admin.key(),
+            fee,
+            fee_recipient: self.fee_recipient.key(),
+            bump: bumps.config,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+    pub fee_recipient: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, EscrowConfig>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_config(&mut self, fee: u16) -> Result<()> {
+        require!(fee <= MAX_FEE_BPS, EscrowError::InvalidFee);
+
+        self.config.fee = fee;
+        self.config.fee_recipient = self.fee_recipient.key();
+
+        Ok(())
+    }
+}
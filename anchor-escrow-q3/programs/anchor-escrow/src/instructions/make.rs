@@ -1,17 +1,22 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::Token, token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
     associated_token::AssociatedToken
 };
 
-use crate::state::Escrow;
-pub use crate::state::*;
+use crate::events::MakeEvent;
+use crate::state::{Escrow, EscrowConfig};
 
 #[derive(Accounts)]
 #[instruction(seed: u64)]
 pub struct Make<'info> {
     #[account(mut)]
     pub maker: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, EscrowConfig>,
     #[account(
         mint::token_program = token_program,
     )]
@@ -57,6 +62,7 @@ impl<'info> Make<'info> {
             mint_a: self.mint_a.key(), 
             mint_b: self.mint_b.key(), 
             receive, 
+            fee: self.config.fee,
             bump: bumps.escrow, 
         });
 
@@ -73,8 +79,18 @@ impl<'info> Make<'info> {
 
         let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
 
-        transfer_checked(cpi_ctx, deposit, self.mint_a.decimals);
-        
+        transfer_checked(cpi_ctx, deposit, self.mint_a.decimals)?;
+
+        emit!(MakeEvent {
+            maker: self.maker.key(),
+            escrow: self.escrow.key(),
+            seed: self.escrow.seed,
+            mint_a: self.mint_a.key(),
+            mint_b: self.mint_b.key(),
+            deposit,
+            receive: self.escrow.receive,
+        });
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+    associated_token::AssociatedToken
+};
+
+use crate::error::EscrowError;
+use crate::state::{Milestone, MilestoneEscrow, MilestoneStatus, MAX_MILESTONES};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct MakeMilestone<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub payee: SystemAccount<'info>,
+    pub arbiter: SystemAccount<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+        associated_token::token_program = token_program,
+    )]
+    pub payer_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"milestone", payer.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump,
+        space = MilestoneEscrow::INIT_SPACE + 8,
+    )]
+    pub milestone_escrow: Account<'info, MilestoneEscrow>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = milestone_escrow,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MakeMilestone<'info> {
+    pub fn init_milestone_escrow(&mut self, seed: u64, amounts: Vec<u64>, bumps: &MakeMilestoneBumps) -> Result<()> {
+        require!(!amounts.is_empty() && amounts.len() <= MAX_MILESTONES, EscrowError::InvalidMilestones);
+        require!(amounts.iter().all(|amount| *amount > 0), EscrowError::InvalidAmount);
+
+        self.milestone_escrow.set_inner(MilestoneEscrow {
+            seed,
+            payer: self.payer.key(),
+            payee: self.payee.key(),
+            arbiter: self.arbiter.key(),
+            mint: self.mint.key(),
+            milestones: amounts
+                .into_iter()
+                .map(|amount| Milestone { amount, status: MilestoneStatus::Pending })
+                .collect(),
+            bump: bumps.milestone_escrow,
+        });
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self) -> Result<()> {
+        let total = self.milestone_escrow.milestones
+            .iter()
+            .try_fold(0u64, |sum, m| sum.checked_add(m.amount))
+            .ok_or(EscrowError::ArithmeticOverflow)?;
+
+        let transfer_accounts = TransferChecked {
+            from: self.payer_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.payer.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+        transfer_checked(cpi_ctx, total, self.mint.decimals)
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+    associated_token::AssociatedToken
+};
+
+use crate::error::EscrowError;
+use crate::state::Vesting;
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct MakeVesting<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = maker,
+        seeds = [b"vesting", maker.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump,
+        space = Vesting::INIT_SPACE + 8,
+    )]
+    pub vesting: Account<'info, Vesting>,
+    #[account(
+        init,
+        payer = maker,
+        associated_token::mint = mint_a,
+        associated_token::authority = vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MakeVesting<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn init_vesting(
+        &mut self,
+        seed: u64,
+        total: u64,
+        start: i64,
+        cliff: i64,
+        end: i64,
+        revocable: bool,
+        bumps: &MakeVestingBumps,
+    ) -> Result<()> {
+        require!(total > 0, EscrowError::InvalidAmount);
+        require!(start <= cliff && cliff <= end && start < end, EscrowError::InvalidSchedule);
+
+        self.vesting.set_inner(Vesting {
+            seed,
+            maker: self.maker.key(),
+            beneficiary: self.beneficiary.key(),
+            mint_a: self.mint_a.key(),
+            total,
+            claimed: 0,
+            start,
+            cliff,
+            end,
+            revocable,
+            bump: bumps.vesting,
+        });
+
+        Ok(())
+    }
+
+    pub fn deposit(&mut self) -> Result<()> {
+        let transfer_accounts = TransferChecked {
+            from: self.maker_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.maker.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+        transfer_checked(cpi_ctx, self.vesting.total, self.mint_a.decimals)
+    }
+}
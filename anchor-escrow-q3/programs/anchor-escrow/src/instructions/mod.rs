@@ -1,7 +1,31 @@
+pub mod cancel_milestone;
+pub mod cancel_order;
+pub mod claim;
+pub mod dispute_milestone;
+pub mod initialize_config;
 pub mod make;
+pub mod make_milestone;
+pub mod make_vesting;
 pub mod refund;
+pub mod release_milestone;
+pub mod resolve_dispute;
+pub mod revoke;
 pub mod take;
+pub mod take_order;
+pub mod update;
 
+pub use cancel_milestone::*;
+pub use cancel_order::*;
+pub use claim::*;
+pub use dispute_milestone::*;
+pub use initialize_config::*;
 pub use make::*;
+pub use make_milestone::*;
+pub use make_vesting::*;
 pub use refund::*;
-pub use take::*;
\ No newline at end of file
+pub use release_milestone::*;
+pub use resolve_dispute::*;
+pub use revoke::*;
+pub use take::*;
+pub use take_order::*;
+pub use update::*;
\ No newline at end of file
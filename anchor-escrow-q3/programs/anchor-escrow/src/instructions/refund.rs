@@ -4,8 +4,8 @@ use anchor_spl::{
     token_interface::{CloseAccount, close_account, transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked}
 };
 
+use crate::events::RefundEvent;
 use crate::state::Escrow;
-pub use crate::state::*;
 
 #[derive(Accounts)]
 pub struct Refund<'info> {
@@ -31,6 +31,7 @@ pub struct Refund<'info> {
     )]
     pub escrow: Account<'info, Escrow>,
     #[account(
+        mut,
         associated_token::mint = mint_a,
         associated_token::authority = escrow,
         associated_token::token_program = token_program,
@@ -62,6 +63,13 @@ impl<'info> Refund<'info> {
         
         transfer_checked(cpi_ctx, self.vault.amount, self.mint_a.decimals)?;
 
+        emit!(RefundEvent {
+            maker: self.maker.key(),
+            escrow: self.escrow.key(),
+            mint_a: self.mint_a.key(),
+            amount: self.vault.amount,
+        });
+
         let close_accounts = CloseAccount {
             account: self.vault.to_account_info(),
             destination: self.maker.to_account_info(),
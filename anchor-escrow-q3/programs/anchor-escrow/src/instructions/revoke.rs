@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, CloseAccount, transfer_checked, close_account},
+    associated_token::AssociatedToken
+};
+
+use crate::error::EscrowError;
+use crate::state::Vesting;
+
+#[derive(Accounts)]
+pub struct Revoke<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub beneficiary: SystemAccount<'info>,
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = maker,
+        associated_token::mint = mint_a,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        close = maker,
+        has_one = maker,
+        has_one = beneficiary,
+        has_one = mint_a,
+        seeds = [b"vesting", maker.key().as_ref(), vesting.seed.to_le_bytes().as_ref()],
+        bump = vesting.bump,
+    )]
+    pub vesting: Account<'info, Vesting>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = vesting,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Revoke<'info> {
+    // Pays out whatever has vested but not been claimed, returns the unvested remainder to the maker
+    pub fn revoke_and_close(&mut self) -> Result<()> {
+        require!(self.vesting.revocable, EscrowError::NotRevocable);
+
+        let now = Clock::get()?.unix_timestamp;
+        let vested = self.vesting.vested_amount(now).ok_or(EscrowError::ArithmeticOverflow)?;
+        let owed = vested.checked_sub(self.vesting.claimed).ok_or(EscrowError::ArithmeticOverflow)?;
+        let unvested = self.vault.amount.checked_sub(owed).ok_or(EscrowError::ArithmeticOverflow)?;
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"vesting",
+            self.maker.to_account_info().key.as_ref(),
+            &self.vesting.seed.to_le_bytes()[..],
+            &[self.vesting.bump]
+        ]];
+
+        if owed > 0 {
+            let transfer_accounts = TransferChecked {
+                from: self.vault.to_account_info(),
+                mint: self.mint_a.to_account_info(),
+                to: self.beneficiary_ata_a.to_account_info(),
+                authority: self.vesting.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), transfer_accounts, &signer_seeds);
+
+            transfer_checked(cpi_ctx, owed, self.mint_a.decimals)?;
+        }
+
+        if unvested > 0 {
+            let transfer_accounts = TransferChecked {
+                from: self.vault.to_account_info(),
+                mint: self.mint_a.to_account_info(),
+                to: self.maker_ata_a.to_account_info(),
+                authority: self.vesting.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), transfer_accounts, &signer_seeds);
+
+            transfer_checked(cpi_ctx, unvested, self.mint_a.decimals)?;
+        }
+
+        let close_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.maker.to_account_info(),
+            authority: self.vesting.to_account_info()
+        };
+
+        let close_cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), close_accounts, &signer_seeds);
+
+        close_account(close_cpi_ctx)?;
+
+        Ok(())
+    }
+}
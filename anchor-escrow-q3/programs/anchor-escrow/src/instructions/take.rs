@@ -4,8 +4,9 @@ use anchor_spl::{
     associated_token::AssociatedToken
 };
 
-use crate::state::Escrow;
-pub use crate::state::*;
+use crate::error::EscrowError;
+use crate::events::TakeEvent;
+use crate::state::{fee_on, Escrow, EscrowConfig};
 
 #[derive(Accounts)]
 pub struct Take<'info> {
@@ -13,6 +14,13 @@ pub struct Take<'info> {
     pub taker: Signer<'info>,
     #[account(mut)]
     pub maker: SystemAccount<'info>,
+    pub fee_recipient: SystemAccount<'info>,
+    #[account(
+        has_one = fee_recipient,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, EscrowConfig>,
     #[account(
         mint::token_program = token_program,
     )]
@@ -28,6 +36,14 @@ pub struct Take<'info> {
         associated_token::token_program = token_program,
     )]
     pub maker_ata_b: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = mint_b,
+        associated_token::authority = fee_recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_recipient_ata_b: InterfaceAccount<'info, TokenAccount>,
     #[account(
         init_if_needed,
         payer = taker,
@@ -67,7 +83,20 @@ pub struct Take<'info> {
 }
 
 impl<'info> Take<'info> {
+    // The maker can `update` the price at any time, so the taker pins the terms they saw.
+    // The vault can only grow through donations, so the deposit is a lower bound.
+    pub fn check_terms(&self, expected_deposit: u64, expected_receive: u64) -> Result<()> {
+        require!(self.vault.amount >= expected_deposit, EscrowError::TermsChanged);
+        require_eq!(self.escrow.receive, expected_receive, EscrowError::TermsChanged);
+
+        Ok(())
+    }
+
     pub fn deposit(&mut self) -> Result<()> {
+        // Charged at the rate in force when the escrow was made
+        let fee = fee_on(self.escrow.receive, self.escrow.fee)?;
+        let amount = self.escrow.receive.checked_sub(fee).ok_or(EscrowError::ArithmeticOverflow)?;
+
         let transfer_accounts = TransferChecked {
             from: self.taker_ata_b.to_account_info(),
             mint: self.mint_b.to_account_info(),
@@ -77,7 +106,20 @@ impl<'info> Take<'info> {
 
         let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
 
-        transfer_checked(cpi_ctx, self.escrow.receive, self.mint_b.decimals)?;
+        transfer_checked(cpi_ctx, amount, self.mint_b.decimals)?;
+
+        if fee > 0 {
+            let transfer_accounts = TransferChecked {
+                from: self.taker_ata_b.to_account_info(),
+                mint: self.mint_b.to_account_info(),
+                to: self.fee_recipient_ata_b.to_account_info(),
+                authority: self.taker.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+            transfer_checked(cpi_ctx, fee, self.mint_b.decimals)?;
+        }
 
         Ok(())
     }
@@ -101,6 +143,16 @@ impl<'info> Take<'info> {
         
         transfer_checked(cpi_ctx, self.vault.amount, self.mint_a.decimals)?;
 
+        emit!(TakeEvent {
+            taker: self.taker.key(),
+            maker: self.maker.key(),
+            escrow: self.escrow.key(),
+            mint_a: self.mint_a.key(),
+            mint_b: self.mint_b.key(),
+            deposit: self.vault.amount,
+            receive: self.escrow.receive,
+        });
+
         let close_accounts = CloseAccount {
             account: self.vault.to_account_info(),
             destination: self.maker.to_account_info(),
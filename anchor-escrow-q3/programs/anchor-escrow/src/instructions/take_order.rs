@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{self as instructions_sysvar, load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+    associated_token::AssociatedToken
+};
+
+use crate::error::EscrowError;
+use crate::state::{fee_on, EscrowConfig, Order, OrderNonce};
+
+// Layout of the ed25519 program instruction data
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+
+#[derive(Accounts)]
+#[instruction(order: Order)]
+pub struct TakeOrder<'info> {
+    #[account(
+        mut,
+        constraint = !matches!(order.taker, Some(taker_key) if taker_key != taker.key()) @ EscrowError::OrderMismatch,
+    )]
+    pub taker: Signer<'info>,
+    #[account(
+        constraint = maker.key() == order.maker @ EscrowError::OrderMismatch,
+    )]
+    pub maker: SystemAccount<'info>,
+    pub fee_recipient: SystemAccount<'info>,
+    #[account(
+        has_one = fee_recipient,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, EscrowConfig>,
+    #[account(
+        mint::token_program = token_program,
+        constraint = mint_a.key() == order.mint_a @ EscrowError::OrderMismatch,
+    )]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        mint::token_program = token_program,
+        constraint = mint_b.key() == order.mint_b @ EscrowError::OrderMismatch,
+    )]
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_ata_b: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = mint_b,
+        associated_token::authority = fee_recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_recipient_ata_b: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_ata_b: InterfaceAccount<'info, TokenAccount>,
+    // Maker approves this PDA as delegate on `maker_ata_a` before quoting
+    #[account(
+        seeds = [b"delegate", maker.key().as_ref()],
+        bump,
+    )]
+    pub delegate: SystemAccount<'info>,
+    #[account(
+        init,
+        payer = taker,
+        seeds = [b"nonce", maker.key().as_ref(), order.nonce.to_le_bytes().as_ref()],
+        bump,
+        space = OrderNonce::INIT_SPACE + 8,
+    )]
+    pub order_nonce: Account<'info, OrderNonce>,
+    /// CHECK: address is checked against the instructions sysvar id
+    #[account(address = instructions_sysvar::ID)]
+    pub instruction_sysvar: AccountInfo<'info>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TakeOrder<'info> {
+    // The ed25519 program instruction must immediately precede this one and carry
+    // exactly one signature by the maker over `order.to_slice()`
+    pub fn verify_ed25519_signature(&mut self, order: &Order) -> Result<()> {
+        let current_index = load_current_index_checked(&self.instruction_sysvar.to_account_info())?;
+        require!(current_index > 0, EscrowError::InvalidSignatureInstruction);
+
+        let ix = load_instruction_at_checked(
+            (current_index - 1) as usize,
+            &self.instruction_sysvar.to_account_info()
+        )?;
+
+        require_keys_eq!(ix.program_id, ed25519_program::ID, EscrowError::InvalidSignatureInstruction);
+        require_eq!(ix.accounts.len(), 0, EscrowError::InvalidSignatureInstruction);
+
+        let data = &ix.data;
+        require!(data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE, EscrowError::InvalidSignatureInstruction);
+        require_eq!(data[0], 1, EscrowError::InvalidSignatureInstruction); // only one signature passed
+
+        let offsets = &data[SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE];
+        let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+
+        let signature_ix_index = read_u16(2);
+        let pubkey_offset = read_u16(4) as usize;
+        let pubkey_ix_index = read_u16(6);
+        let message_offset = read_u16(8) as usize;
+        let message_size = read_u16(10) as usize;
+        let message_ix_index = read_u16(12);
+
+        // Signature, key and message must all live in the ed25519 instruction itself
+        require!(
+            signature_ix_index == u16::MAX && pubkey_ix_index == u16::MAX && message_ix_index == u16::MAX,
+            EscrowError::InvalidSignatureInstruction
+        );
+
+        let pubkey = data.get(pubkey_offset..pubkey_offset + PUBKEY_SIZE)
+            .ok_or(EscrowError::InvalidSignatureInstruction)?;
+        let message = data.get(message_offset..message_offset + message_size)
+            .ok_or(EscrowError::InvalidSignatureInstruction)?;
+
+        require!(pubkey == self.maker.key().as_ref(), EscrowError::InvalidSignature); // verify maker is the signer
+        require!(message == order.to_slice().as_slice(), EscrowError::InvalidSignature); // verify message is the order
+
+        Ok(())
+    }
+
+    pub fn use_nonce(&mut self, order: &Order, bumps: &TakeOrderBumps) -> Result<()> {
+        require!(Clock::get()?.unix_timestamp <= order.expiry, EscrowError::OrderExpired);
+
+        self.order_nonce.set_inner(OrderNonce {
+            maker: order.maker,
+            nonce: order.nonce,
+            bump: bumps.order_nonce,
+        });
+
+        Ok(())
+    }
+
+    pub fn settle(&mut self, order: &Order, bumps: &TakeOrderBumps) -> Result<()> {
+        // Quotes pay the signed protocol fee out of the maker's mint_b proceeds, which can't
+        // undercut the current config but is not raised by later config updates
+        require!(order.fee >= self.config.fee, EscrowError::InvalidFee);
+        let fee = fee_on(order.receive, order.fee)?;
+        let amount = order.receive.checked_sub(fee).ok_or(EscrowError::ArithmeticOverflow)?;
+
+        let transfer_accounts = TransferChecked {
+            from: self.taker_ata_b.to_account_info(),
+            mint: self.mint_b.to_account_info(),
+            to: self.maker_ata_b.to_account_info(),
+            authority: self.taker.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+        transfer_checked(cpi_ctx, amount, self.mint_b.decimals)?;
+
+        if fee > 0 {
+            let transfer_accounts = TransferChecked {
+                from: self.taker_ata_b.to_account_info(),
+                mint: self.mint_b.to_account_info(),
+                to: self.fee_recipient_ata_b.to_account_info(),
+                authority: self.taker.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), transfer_accounts);
+
+            transfer_checked(cpi_ctx, fee, self.mint_b.decimals)?;
+        }
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"delegate",
+            self.maker.to_account_info().key.as_ref(),
+            &[bumps.delegate]
+        ]];
+
+        let transfer_accounts = TransferChecked {
+            from: self.maker_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.taker_ata_a.to_account_info(),
+            authority: self.delegate.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), transfer_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, order.deposit, self.mint_a.decimals)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::error::EscrowError;
+use crate::events::UpdateEvent;
+use crate::state::Escrow;
+
+#[derive(Accounts)]
+pub struct Update<'info> {
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        seeds = [b"escrow", maker.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+impl<'info> Update<'info> {
+    pub fn update_receive(&mut self, receive: u64) -> Result<()> {
+        require!(receive > 0, EscrowError::InvalidAmount);
+
+        self.escrow.receive = receive;
+
+        emit!(UpdateEvent {
+            maker: self.maker.key(),
+            escrow: self.escrow.key(),
+            mint_a: self.escrow.mint_a,
+            mint_b: self.escrow.mint_b,
+            receive,
+        });
+
+        Ok(())
+    }
+}
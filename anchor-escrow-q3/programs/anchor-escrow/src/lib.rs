@@ -2,6 +2,8 @@
 
 use anchor_lang::prelude::*;
 
+pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
@@ -14,13 +16,86 @@ declare_id!("BSReNYABsYyr7ycGXbfsoun5cNNc86RNaqk1QSu9yqqx");
 pub mod anchor_escrow {
     use super::*;
 
+    pub fn initialize_config(ctx: Context<InitializeConfig>, fee: u16) -> Result<()> {
+        ctx.accounts.init_config(fee, &ctx.bumps)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, fee: u16) -> Result<()> {
+        ctx.accounts.update_config(fee)
+    }
+
     pub fn initialize(ctx: Context<Make>, seed: u64, deposit: u64, receive: u64) -> Result<()> {
         ctx.accounts.init_escrow(seed, receive, &ctx.bumps)?;
         ctx.accounts.deposit(deposit)
     }
 
-    pub fn take(ctx: Context<Make>, seed: u64, deposit: u64, receive: u64) -> Result<()> {
-        ctx.accounts.init_escrow(seed, receive, &ctx.bumps)?;
-        ctx.accounts.deposit(deposit)
+    pub fn take(ctx: Context<Take>, expected_deposit: u64, expected_receive: u64) -> Result<()> {
+        ctx.accounts.check_terms(expected_deposit, expected_receive)?;
+        ctx.accounts.deposit()?;
+        ctx.accounts.withdraw_and_close_vault()
+    }
+
+    pub fn refund(ctx: Context<Refund>) -> Result<()> {
+        ctx.accounts.refund_and_close()
+    }
+
+    pub fn update(ctx: Context<Update>, receive: u64) -> Result<()> {
+        ctx.accounts.update_receive(receive)
+    }
+
+    pub fn take_order(ctx: Context<TakeOrder>, order: Order) -> Result<()> {
+        ctx.accounts.verify_ed25519_signature(&order)?;
+        ctx.accounts.use_nonce(&order, &ctx.bumps)?;
+        ctx.accounts.settle(&order, &ctx.bumps)
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>, nonce: u64) -> Result<()> {
+        ctx.accounts.cancel_order(nonce, &ctx.bumps)
+    }
+
+    pub fn make_vesting(
+        ctx: Context<MakeVesting>,
+        seed: u64,
+        amount: u64,
+        start: i64,
+        cliff: i64,
+        end: i64,
+        revocable: bool,
+    ) -> Result<()> {
+        ctx.accounts.init_vesting(seed, amount, start, cliff, end, revocable, &ctx.bumps)?;
+        ctx.accounts.deposit()
+    }
+
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        ctx.accounts.claim()?;
+        ctx.accounts.close_if_complete()
+    }
+
+    pub fn revoke(ctx: Context<Revoke>) -> Result<()> {
+        ctx.accounts.revoke_and_close()
+    }
+
+    pub fn make_milestone(ctx: Context<MakeMilestone>, seed: u64, amounts: Vec<u64>) -> Result<()> {
+        ctx.accounts.init_milestone_escrow(seed, amounts, &ctx.bumps)?;
+        ctx.accounts.deposit()
+    }
+
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>, index: u8) -> Result<()> {
+        ctx.accounts.release(index)?;
+        ctx.accounts.close_if_settled()
+    }
+
+    pub fn cancel_milestone(ctx: Context<CancelMilestone>, index: u8) -> Result<()> {
+        ctx.accounts.cancel(index)?;
+        ctx.accounts.close_if_settled()
+    }
+
+    pub fn dispute_milestone(ctx: Context<DisputeMilestone>, index: u8) -> Result<()> {
+        ctx.accounts.dispute(index)
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, index: u8, payee_amount: u64) -> Result<()> {
+        ctx.accounts.resolve(index, payee_amount)?;
+        ctx.accounts.close_if_settled()
     }
 }
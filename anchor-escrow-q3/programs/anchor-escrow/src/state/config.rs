@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::error::EscrowError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct EscrowConfig {
+    pub admin: Pubkey,
+    pub fee: u16, // Fee in basis points, charged on the taker's mint_b payment
+    pub fee_recipient: Pubkey,
+    pub bump: u8,
+}
+
+// Fee of `fee` basis points owed on a mint_b payment of `amount`, rounded down
+pub fn fee_on(amount: u64, fee: u16) -> Result<u64> {
+    let fee = (amount as u128)
+        .checked_mul(fee as u128).ok_or(EscrowError::ArithmeticOverflow)?
+        .checked_div(10_000).ok_or(EscrowError::ArithmeticOverflow)?;
+
+    Ok(fee as u64)
+}
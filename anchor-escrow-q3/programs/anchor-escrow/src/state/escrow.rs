@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Escrow {
+    pub seed: u64,
+    pub maker: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub receive: u64,
+    pub fee: u16, // Protocol fee in basis points, fixed when the escrow is made
+    pub bump: u8,
+}
+
+// Byte offsets (including the 8 byte discriminator) for `getProgramAccounts` memcmp
+// filters, so clients can list open offers by maker or by mint pair
+impl Escrow {
+    pub const MAKER_OFFSET: usize = 8 + 8;
+    pub const MINT_A_OFFSET: usize = Self::MAKER_OFFSET + 32;
+    pub const MINT_B_OFFSET: usize = Self::MINT_A_OFFSET + 32;
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, CloseAccount, transfer_checked, close_account};
+
+pub const MAX_MILESTONES: usize = 10;
+
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneEscrow {
+    pub seed: u64,
+    pub payer: Pubkey,
+    pub payee: Pubkey,
+    pub arbiter: Pubkey,
+    pub mint: Pubkey,
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    pub bump: u8,
+}
+
+impl MilestoneEscrow {
+    pub fn is_settled(&self) -> bool {
+        self.milestones.iter().all(|m| matches!(
+            m.status,
+            MilestoneStatus::Released | MilestoneStatus::Resolved | MilestoneStatus::Cancelled
+        ))
+    }
+}
+
+// Closes the vault and escrow to the payer once every milestone is settled, first returning
+// anything left in the vault, such as donations, so the close can't be blocked
+pub fn close_if_settled<'info>(
+    milestone_escrow: &mut Account<'info, MilestoneEscrow>,
+    vault: &mut InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    payer: &AccountInfo<'info>,
+    payer_ata: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    if !milestone_escrow.is_settled() {
+        return Ok(());
+    }
+
+    let signer_seeds: [&[&[u8]]; 1] = [&[
+        b"milestone",
+        payer.key.as_ref(),
+        &milestone_escrow.seed.to_le_bytes()[..],
+        &[milestone_escrow.bump]
+    ]];
+
+    vault.reload()?;
+    if vault.amount > 0 {
+        let transfer_accounts = TransferChecked {
+            from: vault.to_account_info(),
+            mint: mint.to_account_info(),
+            to: payer_ata.to_account_info(),
+            authority: milestone_escrow.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), transfer_accounts, &signer_seeds);
+
+        transfer_checked(cpi_ctx, vault.amount, mint.decimals)?;
+    }
+
+    let close_accounts = CloseAccount {
+        account: vault.to_account_info(),
+        destination: payer.clone(),
+        authority: milestone_escrow.to_account_info()
+    };
+
+    let close_cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), close_accounts, &signer_seeds);
+
+    close_account(close_cpi_ctx)?;
+
+    milestone_escrow.close(payer.clone())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Milestone {
+    pub amount: u64,
+    pub status: MilestoneStatus,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, Eq, PartialEq, Copy)]
+pub enum MilestoneStatus {
+    Pending = 0,
+    Released = 1,
+    Disputed = 2,
+    Resolved = 3,
+    Cancelled = 4,
+}
@@ -1,12 +1,11 @@
-use anchor_lang::prelude::*;
+pub mod config;
+pub mod escrow;
+pub mod milestone;
+pub mod order;
+pub mod vesting;
 
-#[account]
-#[derive(InitSpace)]
-pub struct Escrow {
-    pub seed: u64,
-    pub maker: Pubkey,
-    pub mint_a: Pubkey,
-    pub mint_b: Pubkey,
-    pub receive: u64,
-    pub bump: u8,
-}
+pub use config::*;
+pub use escrow::*;
+pub use milestone::*;
+pub use order::*;
+pub use vesting::*;
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+// Quote signed off-chain by the maker and settled by the taker via `take_order`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Order {
+    pub maker: Pubkey,
+    pub taker: Option<Pubkey>, // Restricts the quote to one taker when set
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub deposit: u64,
+    pub receive: u64,
+    pub fee: u16, // Protocol fee in basis points the maker agreed to
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
+impl Order {
+    // Message bytes the maker signs with ed25519, prefixed with the program id for domain separation
+    pub fn to_slice(&self) -> Vec<u8> {
+        let mut s = crate::ID.to_bytes().to_vec();
+        s.extend_from_slice(&self.maker.to_bytes());
+        match self.taker {
+            Some(taker) => {
+                s.push(1);
+                s.extend_from_slice(&taker.to_bytes());
+            }
+            None => s.push(0),
+        }
+        s.extend_from_slice(&self.mint_a.to_bytes());
+        s.extend_from_slice(&self.mint_b.to_bytes());
+        s.extend_from_slice(&self.deposit.to_le_bytes());
+        s.extend_from_slice(&self.receive.to_le_bytes());
+        s.extend_from_slice(&self.fee.to_le_bytes());
+        s.extend_from_slice(&self.expiry.to_le_bytes());
+        s.extend_from_slice(&self.nonce.to_le_bytes());
+        s
+    }
+}
+
+// Marks a maker's order nonce as used so a signed quote can only be filled once, or cancelled
+#[account]
+#[derive(InitSpace)]
+pub struct OrderNonce {
+    pub maker: Pubkey,
+    pub nonce: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vesting {
+    pub seed: u64,
+    pub maker: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint_a: Pubkey,
+    pub total: u64,
+    pub claimed: u64,
+    pub start: i64,
+    pub cliff: i64,
+    pub end: i64,
+    pub revocable: bool,
+    pub bump: u8,
+}
+
+impl Vesting {
+    // Amount unlocked at `now`: nothing before the cliff, linear from start to end
+    pub fn vested_amount(&self, now: i64) -> Option<u64> {
+        if now < self.cliff {
+            return Some(0);
+        }
+        if now >= self.end {
+            return Some(self.total);
+        }
+
+        let elapsed = now.checked_sub(self.start)? as u128;
+        let duration = self.end.checked_sub(self.start)? as u128;
+
+        (self.total as u128)
+            .checked_mul(elapsed)?
+            .checked_div(duration)?
+            .try_into()
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vesting(total: u64, start: i64, cliff: i64, end: i64) -> Vesting {
+        Vesting {
+            seed: 0,
+            maker: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            mint_a: Pubkey::default(),
+            total,
+            claimed: 0,
+            start,
+            cliff,
+            end,
+            revocable: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let v = vesting(1_000, 100, 150, 200);
+        assert_eq!(v.vested_amount(0), Some(0));
+        assert_eq!(v.vested_amount(149), Some(0));
+    }
+
+    #[test]
+    fn vests_linearly_from_start_once_past_the_cliff() {
+        let v = vesting(1_000, 100, 150, 200);
+        assert_eq!(v.vested_amount(150), Some(500));
+        assert_eq!(v.vested_amount(175), Some(750));
+        // Rounds down
+        assert_eq!(vesting(10, 0, 0, 3).vested_amount(1), Some(3));
+    }
+
+    #[test]
+    fn everything_vests_at_the_end() {
+        let v = vesting(1_000, 100, 150, 200);
+        assert_eq!(v.vested_amount(200), Some(1_000));
+        assert_eq!(v.vested_amount(i64::MAX), Some(1_000));
+    }
+
+    #[test]
+    fn large_totals_do_not_overflow() {
+        let v = vesting(u64::MAX, 0, 0, 4);
+        assert_eq!(v.vested_amount(2), Some(u64::MAX / 2));
+    }
+}
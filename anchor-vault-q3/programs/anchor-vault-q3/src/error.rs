@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum VaultError {
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Vault does not have enough funds")]
+    InsufficientFunds,
+    #[msg("Withdrawal would leave the vault below the rent-exempt minimum")]
+    RentExemptViolation,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Vault is locked until its unlock date")]
+    VaultLocked,
+    #[msg("Unlock date can only be extended")]
+    LockShortened,
+    #[msg("Owners must be unique and between 1 and 10")]
+    InvalidOwners,
+    #[msg("Threshold must be between 1 and the number of owners")]
+    InvalidThreshold,
+    #[msg("Signer is not an owner of this multisig vault")]
+    NotAnOwner,
+    #[msg("Owner has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal has not reached the approval threshold")]
+    ThresholdNotReached,
+    #[msg("Allowance period must be positive and expiry in the future")]
+    InvalidAllowance,
+    #[msg("Allowance has expired")]
+    AllowanceExpired,
+    #[msg("Withdrawal exceeds the remaining allowance for this period")]
+    AllowanceExceeded,
+    #[msg("Schedule needs a positive interval and at least one payment")]
+    InvalidSchedule,
+    #[msg("Scheduled payment is not due yet")]
+    PaymentNotDue,
+    #[msg("Inactivity window must be positive")]
+    InvalidInactivityWindow,
+    #[msg("Signer is not the vault beneficiary")]
+    NotBeneficiary,
+    #[msg("Owner is still active")]
+    OwnerStillActive,
+    #[msg("Deposit is too small to mint any shares")]
+    ZeroShares,
+    #[msg("Withdrawal exceeds the per-window cap; queue it instead")]
+    WithdrawalCapExceeded,
+    #[msg("Rate limit window and delay must be positive")]
+    InvalidRateLimit,
+    #[msg("Loosening the rate limit or changing the beneficiary requires the guardian's signature")]
+    GuardianRequired,
+    #[msg("Queued withdrawal is still in its delay period")]
+    WithdrawalDelayNotElapsed,
+    #[msg("Only the owner or guardian may cancel a queued withdrawal")]
+    NotOwnerOrGuardian,
+    #[msg("Revoke all allowances before closing the vault")]
+    AllowancesOpen,
+    #[msg("Cancel or finish all schedules before closing the vault")]
+    SchedulesOpen,
+    #[msg("Execute or cancel all queued withdrawals before closing the vault")]
+    WithdrawalsQueued,
+    #[msg("Withdraw and close all token balances before closing the vault")]
+    TokenBalancesOpen,
+    #[msg("Withdraw the token balance before closing it")]
+    TokenBalanceNotEmpty,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{MultisigState, WithdrawalProposal};
+
+#[derive(Accounts)]
+pub struct ApproveWithdrawal<'info> {
+    pub owner: Signer<'info>,
+    #[account{
+        constraint = multisig_state.is_owner(&owner.key()) @ VaultError::NotAnOwner,
+    }]
+    pub multisig_state: Account<'info, MultisigState>,
+    #[account{
+        mut,
+        seeds = [b"proposal", multisig_state.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    }]
+    pub proposal: Account<'info, WithdrawalProposal>,
+}
+
+impl<'info> ApproveWithdrawal<'info> {
+    pub fn approve_withdrawal(&mut self) -> Result<()> {
+        let owner = self.owner.key();
+
+        require!(!self.proposal.approvals.contains(&owner), VaultError::AlreadyApproved);
+
+        self.proposal.approvals.push(owner);
+
+        Ok(())
+    }
+}
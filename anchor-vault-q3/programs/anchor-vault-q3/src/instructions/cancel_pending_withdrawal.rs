@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{PendingWithdrawal, VaultState};
+
+#[derive(Accounts)]
+pub struct CancelPendingWithdrawal<'info> {
+    #[account{
+        constraint = authority.key() == user.key() || Some(authority.key()) == vault_state.guardian @ VaultError::NotOwnerOrGuardian,
+    }]
+    pub authority: Signer<'info>,
+    #[account{mut}]
+    pub user: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        close = user,
+        seeds = [b"pending", vault_state.key().as_ref(), pending_withdrawal.index.to_le_bytes().as_ref()],
+        bump = pending_withdrawal.bump,
+    }]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+}
+
+impl<'info> CancelPendingWithdrawal<'info> {
+    pub fn cancel_pending_withdrawal(&mut self) -> Result<()> {
+        self.vault_state.queued_count = self.vault_state.queued_count.checked_sub(1).ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
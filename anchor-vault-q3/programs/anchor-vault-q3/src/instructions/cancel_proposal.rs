@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{MultisigState, WithdrawalProposal};
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account{
+        constraint = signer.key() == proposal.proposer || multisig_state.is_owner(&signer.key()) @ VaultError::NotAnOwner,
+    }]
+    pub signer: Signer<'info>,
+    #[account{mut}]
+    pub proposer: SystemAccount<'info>,
+    pub multisig_state: Account<'info, MultisigState>,
+    // Closing the proposal returns its rent to the proposer
+    #[account{
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [b"proposal", multisig_state.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    }]
+    pub proposal: Account<'info, WithdrawalProposal>,
+}
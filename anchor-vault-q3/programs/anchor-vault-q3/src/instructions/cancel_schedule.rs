@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{Schedule, VaultState};
+
+#[derive(Accounts)]
+pub struct CancelSchedule<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        close = user,
+        seeds = [b"schedule", vault_state.key().as_ref(), schedule.seed.to_le_bytes().as_ref()],
+        bump = schedule.bump,
+    }]
+    pub schedule: Account<'info, Schedule>,
+}
+
+impl<'info> CancelSchedule<'info> {
+    pub fn cancel_schedule(&mut self) -> Result<()> {
+        self.vault_state.open_schedules = self.vault_state.open_schedules.checked_sub(1).ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::VaultState;
+
+#[derive(Accounts)]
+pub struct ClaimInheritance<'info> {
+    #[account{mut}]
+    pub beneficiary: Signer<'info>,
+    pub user: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"vault", vault_state.key().as_ref()],
+        bump = vault_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    // Left open: it owns the token vaults the beneficiary sweeps with `claim_inheritance_token`
+    #[account{
+        constraint = vault_state.beneficiary == Some(beneficiary.key()) @ VaultError::NotBeneficiary,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimInheritance<'info> {
+    // Sweeps every lamport in the vault to the beneficiary once the owner's inactivity window lapses
+    pub fn claim_inheritance(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!self.vault_state.is_locked(now), VaultError::VaultLocked);
+        require!(self.vault_state.is_inactive(now), VaultError::OwnerStillActive);
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.beneficiary.to_account_info(),
+        };
+
+        let seeds = &[
+            b"vault".as_ref(),
+            self.vault_state.to_account_info().key.as_ref(),
+            &[self.vault_state.vault_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, self.vault.lamports())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked, close_account, transfer_checked},
+};
+
+use crate::error::VaultError;
+use crate::state::{TokenBalance, VaultState};
+
+#[derive(Accounts)]
+pub struct ClaimInheritanceToken<'info> {
+    #[account{mut}]
+    pub beneficiary: Signer<'info>,
+    pub user: SystemAccount<'info>,
+    #[account{
+        mint::token_program = token_program,
+    }]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account{
+        init_if_needed,
+        payer = beneficiary,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    }]
+    pub beneficiary_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        mut,
+        constraint = vault_state.beneficiary == Some(beneficiary.key()) @ VaultError::NotBeneficiary,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_state,
+        associated_token::token_program = token_program,
+    }]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        mut,
+        close = beneficiary,
+        seeds = [b"balance", vault_state.key().as_ref(), mint.key().as_ref()],
+        bump = token_balance.bump,
+    }]
+    pub token_balance: Account<'info, TokenBalance>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimInheritanceToken<'info> {
+    // Sweeps one mint's token vault to the beneficiary once the owner's inactivity window lapses
+    pub fn claim_inheritance_token(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!self.vault_state.is_locked(now), VaultError::VaultLocked);
+        require!(self.vault_state.is_inactive(now), VaultError::OwnerStillActive);
+
+        // The vault token account is owned by the VaultState PDA
+        let seeds = &[
+            b"state".as_ref(),
+            self.user.to_account_info().key.as_ref(),
+            &[self.vault_state.state_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            from: self.token_vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.beneficiary_ata.to_account_info(),
+            authority: self.vault_state.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+        transfer_checked(cpi_ctx, self.token_vault.amount, self.mint.decimals)?;
+
+        let cpi_accounts = CloseAccount {
+            account: self.token_vault.to_account_info(),
+            destination: self.beneficiary.to_account_info(),
+            authority: self.vault_state.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+        close_account(cpi_ctx)?;
+
+        self.vault_state.open_token_balances = self.vault_state.open_token_balances
+            .checked_sub(1)
+            .ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::VaultState;
+
+#[derive(Accounts)]
+pub struct Close<'info> {
+    #[account{mut}]
+    pub user: Signer<'info>,
+    #[account{
+        mut,
+        seeds = [b"vault", vault_state.key().as_ref()],
+        bump = vault_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        mut,
+        close = user,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Close<'info> {
+    pub fn close(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!self.vault_state.is_locked(now), VaultError::VaultLocked);
+        require!(self.vault_state.open_allowances == 0, VaultError::AllowancesOpen);
+        require!(self.vault_state.open_schedules == 0, VaultError::SchedulesOpen);
+        require!(self.vault_state.queued_count == 0, VaultError::WithdrawalsQueued);
+        require!(self.vault_state.open_token_balances == 0, VaultError::TokenBalancesOpen);
+
+        let amount = self.vault.lamports();
+        self.vault_state.record_withdrawal(amount, now)?;
+
+        // Drain the vault PDA entirely; with zero lamports it is reclaimed by the runtime
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.user.to_account_info(),
+        };
+
+        let seeds = &[
+            b"vault".as_ref(),
+            self.vault_state.to_account_info().key.as_ref(),
+            &[self.vault_state.vault_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked, close_account, transfer_checked},
+};
+
+use crate::error::VaultError;
+use crate::state::{TokenBalance, VaultState};
+
+#[derive(Accounts)]
+pub struct CloseToken<'info> {
+    #[account{mut}]
+    pub user: Signer<'info>,
+    #[account{
+        mint::token_program = token_program,
+    }]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account{
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    }]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_state,
+        associated_token::token_program = token_program,
+    }]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        mut,
+        close = user,
+        seeds = [b"balance", vault_state.key().as_ref(), mint.key().as_ref()],
+        bump = token_balance.bump,
+    }]
+    pub token_balance: Account<'info, TokenBalance>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseToken<'info> {
+    // Closes an emptied token vault and its balance, returning the rent to the user
+    pub fn close_token(&mut self) -> Result<()> {
+        require!(self.token_balance.amount == 0, VaultError::TokenBalanceNotEmpty);
+
+        // The vault token account is owned by the VaultState PDA
+        let seeds = &[
+            b"state".as_ref(),
+            self.user.to_account_info().key.as_ref(),
+            &[self.vault_state.state_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        // Anything still in the account was sent directly, not deposited, so it goes to the user
+        if self.token_vault.amount > 0 {
+            let cpi_accounts = TransferChecked {
+                from: self.token_vault.to_account_info(),
+                mint: self.mint.to_account_info(),
+                to: self.user_ata.to_account_info(),
+                authority: self.vault_state.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+            transfer_checked(cpi_ctx, self.token_vault.amount, self.mint.decimals)?;
+        }
+
+        let cpi_accounts = CloseAccount {
+            account: self.token_vault.to_account_info(),
+            destination: self.user.to_account_info(),
+            authority: self.vault_state.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+        close_account(cpi_ctx)?;
+
+        self.vault_state.open_token_balances = self.vault_state.open_token_balances
+            .checked_sub(1)
+            .ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
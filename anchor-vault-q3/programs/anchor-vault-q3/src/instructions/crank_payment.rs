@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::{Schedule, VaultState};
+
+#[derive(Accounts)]
+pub struct CrankPayment<'info> {
+    pub cranker: Signer<'info>,
+    #[account{mut}]
+    pub user: SystemAccount<'info>,
+    #[account{mut}]
+    pub recipient: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"vault", vault_state.key().as_ref()],
+        bump = vault_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        has_one = recipient,
+        seeds = [b"schedule", vault_state.key().as_ref(), schedule.seed.to_le_bytes().as_ref()],
+        bump = schedule.bump,
+    }]
+    pub schedule: Account<'info, Schedule>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CrankPayment<'info> {
+    // Pays a single installment; a keeper that falls behind catches up with repeated cranks
+    pub fn crank_payment(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!self.vault_state.is_locked(now), VaultError::VaultLocked);
+        require!(now >= self.schedule.next_payment_at, VaultError::PaymentNotDue);
+
+        let amount = self.schedule.amount;
+
+        let remaining = self.vault.lamports().checked_sub(amount).ok_or(VaultError::InsufficientFunds)?;
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+        require!(remaining == 0 || remaining >= rent_exempt, VaultError::RentExemptViolation);
+
+        self.vault_state.record_withdrawal(amount, now)?;
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.recipient.to_account_info(),
+        };
+
+        let seeds = &[
+            b"vault".as_ref(),
+            self.vault_state.to_account_info().key.as_ref(),
+            &[self.vault_state.vault_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        self.schedule.remaining = self.schedule.remaining.checked_sub(1).ok_or(VaultError::Overflow)?;
+        self.schedule.next_payment_at = self.schedule.next_payment_at
+            .checked_add(self.schedule.interval)
+            .ok_or(VaultError::Overflow)?;
+
+        // The final payment closes the schedule and refunds its rent to the vault owner
+        if self.schedule.remaining == 0 {
+            self.schedule.close(self.user.to_account_info())?;
+            self.vault_state.open_schedules = self.vault_state.open_schedules.checked_sub(1).ok_or(VaultError::Overflow)?;
+        }
+
+        Ok(())
+    }
+}
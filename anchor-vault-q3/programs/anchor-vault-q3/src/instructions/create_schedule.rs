@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{Schedule, VaultState};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateSchedule<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub recipient: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        init,
+        payer = user,
+        seeds = [b"schedule", vault_state.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + Schedule::INIT_SPACE
+    }]
+    pub schedule: Account<'info, Schedule>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSchedule<'info> {
+    pub fn create_schedule(
+        &mut self,
+        seed: u64,
+        amount: u64,
+        interval: i64,
+        count: u32,
+        start_at: i64,
+        bumps: CreateScheduleBumps,
+    ) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+        require!(interval > 0 && count > 0, VaultError::InvalidSchedule);
+
+        // A back-dated start would let a keeper pay out every missed installment at once
+        let start_at = start_at.max(Clock::get()?.unix_timestamp);
+
+        self.schedule.set_inner(Schedule {
+            seed,
+            vault_state: self.vault_state.key(),
+            recipient: self.recipient.key(),
+            amount,
+            interval,
+            remaining: count,
+            next_payment_at: start_at,
+            bump: bumps.schedule,
+        });
+
+        self.vault_state.open_schedules = self.vault_state.open_schedules.checked_add(1).ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
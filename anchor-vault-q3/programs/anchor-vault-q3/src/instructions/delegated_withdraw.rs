@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::{Allowance, VaultState};
+
+#[derive(Accounts)]
+pub struct DelegatedWithdraw<'info> {
+    pub delegate: Signer<'info>,
+    pub user: SystemAccount<'info>,
+    #[account{mut}]
+    pub destination: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"vault", vault_state.key().as_ref()],
+        bump = vault_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        has_one = destination,
+        seeds = [b"allowance", vault_state.key().as_ref(), delegate.key().as_ref()],
+        bump = allowance.bump,
+    }]
+    pub allowance: Account<'info, Allowance>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DelegatedWithdraw<'info> {
+    pub fn delegated_withdraw(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!self.vault_state.is_locked(now), VaultError::VaultLocked);
+        require!(now < self.allowance.expires_at, VaultError::AllowanceExpired);
+
+        self.allowance.roll_period(now).ok_or(VaultError::Overflow)?;
+
+        let spent = self.allowance.spent.checked_add(amount).ok_or(VaultError::Overflow)?;
+        require!(spent <= self.allowance.amount_per_period, VaultError::AllowanceExceeded);
+
+        let remaining = self.vault.lamports().checked_sub(amount).ok_or(VaultError::InsufficientFunds)?;
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+        require!(remaining == 0 || remaining >= rent_exempt, VaultError::RentExemptViolation);
+
+        self.vault_state.record_withdrawal(amount, now)?;
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.destination.to_account_info(),
+        };
+
+        let seeds = &[
+            b"vault".as_ref(),
+            self.vault_state.to_account_info().key.as_ref(),
+            &[self.vault_state.vault_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        self.allowance.spent = spent;
+
+        Ok(())
+    }
+}
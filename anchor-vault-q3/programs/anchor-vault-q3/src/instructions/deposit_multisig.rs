@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::MultisigState;
+
+#[derive(Accounts)]
+pub struct DepositMultisig<'info> {
+    #[account{mut}]
+    pub depositor: Signer<'info>,
+    pub multisig_state: Account<'info, MultisigState>,
+    #[account{
+        mut,
+        seeds = [b"vault", multisig_state.key().as_ref()],
+        bump = multisig_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositMultisig<'info> {
+    // Anyone may fund a multisig vault
+    pub fn deposit_multisig(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.depositor.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_ctx, amount)?;
+
+        Ok(())
+    }
+}
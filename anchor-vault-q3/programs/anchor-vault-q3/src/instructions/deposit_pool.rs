@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, MintTo, TokenAccount, TokenInterface, mint_to},
+};
+
+use crate::error::VaultError;
+use crate::state::PoolState;
+
+#[derive(Accounts)]
+pub struct DepositPool<'info> {
+    #[account{mut}]
+    pub user: Signer<'info>,
+    #[account{
+        seeds = [b"pool", pool_state.seed.to_le_bytes().as_ref()],
+        bump = pool_state.state_bump,
+        has_one = share_mint,
+    }]
+    pub pool_state: Account<'info, PoolState>,
+    #[account{
+        mut,
+        seeds = [b"vault", pool_state.key().as_ref()],
+        bump = pool_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"shares", pool_state.key().as_ref()],
+        bump = pool_state.mint_bump,
+    }]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    #[account{
+        init_if_needed,
+        payer = user,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    }]
+    pub user_shares: InterfaceAccount<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositPool<'info> {
+    pub fn deposit_pool(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+        let pool_assets = self.vault.lamports().saturating_sub(rent_exempt);
+        let supply = self.share_mint.supply;
+
+        // Deposits mint pro-rata to the pool's assets, starting at a 1:1 price
+        let shares = PoolState::shares_for(amount, pool_assets, supply)?;
+
+        require!(shares > 0, VaultError::ZeroShares);
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_ctx, amount)?;
+
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = MintTo {
+            mint: self.share_mint.to_account_info(),
+            to: self.user_shares.to_account_info(),
+            authority: self.pool_state.to_account_info(),
+        };
+
+        let seed = self.pool_state.seed.to_le_bytes();
+        let seeds = &[
+            b"pool".as_ref(),
+            seed.as_ref(),
+            &[self.pool_state.state_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        mint_to(cpi_ctx, shares)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+};
+
+use crate::error::VaultError;
+use crate::state::{TokenBalance, VaultState};
+
+#[derive(Accounts)]
+pub struct DepositToken<'info> {
+    #[account{mut}]
+    pub user: Signer<'info>,
+    #[account{
+        mint::token_program = token_program,
+    }]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account{
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    }]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = vault_state,
+        associated_token::token_program = token_program,
+    }]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        init_if_needed,
+        payer = user,
+        seeds = [b"balance", vault_state.key().as_ref(), mint.key().as_ref()],
+        bump,
+        space = 8 + TokenBalance::INIT_SPACE
+    }]
+    pub token_balance: Account<'info, TokenBalance>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositToken<'info> {
+    pub fn deposit_token(&mut self, amount: u64, bumps: DepositTokenBumps) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = TransferChecked {
+            from: self.user_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.token_vault.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        let balance_before = self.token_vault.amount;
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        // Credit what actually arrived, which is less than `amount` for Token-2022 transfer-fee mints
+        self.token_vault.reload()?;
+        let received = self.token_vault.amount
+            .checked_sub(balance_before)
+            .ok_or(VaultError::Overflow)?;
+
+        // A freshly created balance has no mint yet
+        if self.token_balance.mint == Pubkey::default() {
+            self.vault_state.open_token_balances = self.vault_state.open_token_balances
+                .checked_add(1)
+                .ok_or(VaultError::Overflow)?;
+        }
+
+        self.token_balance.mint = self.mint.key();
+        self.token_balance.bump = bumps.token_balance;
+        self.token_balance.amount = self.token_balance.amount
+            .checked_add(received)
+            .ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::{PendingWithdrawal, VaultState};
+
+#[derive(Accounts)]
+pub struct ExecutePendingWithdrawal<'info> {
+    pub executor: Signer<'info>,
+    #[account{mut}]
+    pub user: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"vault", vault_state.key().as_ref()],
+        bump = vault_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        close = user,
+        seeds = [b"pending", vault_state.key().as_ref(), pending_withdrawal.index.to_le_bytes().as_ref()],
+        bump = pending_withdrawal.bump,
+    }]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecutePendingWithdrawal<'info> {
+    // Funds always go to the vault owner, so anyone may execute once the delay has elapsed
+    pub fn execute_pending_withdrawal(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!self.vault_state.is_locked(now), VaultError::VaultLocked);
+        require!(now >= self.pending_withdrawal.executable_at, VaultError::WithdrawalDelayNotElapsed);
+
+        let amount = self.pending_withdrawal.amount;
+
+        let remaining = self.vault.lamports().checked_sub(amount).ok_or(VaultError::InsufficientFunds)?;
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+        require!(remaining == 0 || remaining >= rent_exempt, VaultError::RentExemptViolation);
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.user.to_account_info(),
+        };
+
+        let seeds = &[
+            b"vault".as_ref(),
+            self.vault_state.to_account_info().key.as_ref(),
+            &[self.vault_state.vault_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        self.vault_state.queued_count = self.vault_state.queued_count.checked_sub(1).ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
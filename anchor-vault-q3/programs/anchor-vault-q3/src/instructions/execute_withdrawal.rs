@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::{MultisigState, WithdrawalProposal};
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    pub executor: Signer<'info>,
+    #[account{mut}]
+    pub proposer: SystemAccount<'info>,
+    #[account{mut}]
+    pub recipient: SystemAccount<'info>,
+    pub multisig_state: Account<'info, MultisigState>,
+    #[account{
+        mut,
+        seeds = [b"vault", multisig_state.key().as_ref()],
+        bump = multisig_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        mut,
+        close = proposer,
+        has_one = proposer,
+        has_one = recipient,
+        seeds = [b"proposal", multisig_state.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    }]
+    pub proposal: Account<'info, WithdrawalProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteWithdrawal<'info> {
+    // Permissionless once enough owners have approved; the proposal is closed back to its proposer
+    pub fn execute_withdrawal(&mut self) -> Result<()> {
+        require!(
+            self.proposal.approvals.len() >= self.multisig_state.threshold as usize,
+            VaultError::ThresholdNotReached
+        );
+
+        let amount = self.proposal.amount;
+
+        let remaining = self.vault.lamports().checked_sub(amount).ok_or(VaultError::InsufficientFunds)?;
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+        require!(remaining == 0 || remaining >= rent_exempt, VaultError::RentExemptViolation);
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.recipient.to_account_info(),
+        };
+
+        let seeds = &[
+            b"vault".as_ref(),
+            self.multisig_state.to_account_info().key.as_ref(),
+            &[self.multisig_state.vault_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        Ok(())
+    }
+}
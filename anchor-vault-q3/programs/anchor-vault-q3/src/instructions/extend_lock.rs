@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::VaultState;
+
+#[derive(Accounts)]
+pub struct ExtendLock<'info> {
+    pub user: Signer<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+}
+
+impl<'info> ExtendLock<'info> {
+    pub fn extend_lock(&mut self, unlock_at: i64) -> Result<()> {
+        if let Some(current) = self.vault_state.unlock_at {
+            require!(unlock_at >= current, VaultError::LockShortened);
+        }
+
+        self.vault_state.unlock_at = Some(unlock_at);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{Allowance, VaultState};
+
+#[derive(Accounts)]
+pub struct GrantAllowance<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub delegate: SystemAccount<'info>,
+    pub destination: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        init,
+        payer = user,
+        seeds = [b"allowance", vault_state.key().as_ref(), delegate.key().as_ref()],
+        bump,
+        space = 8 + Allowance::INIT_SPACE
+    }]
+    pub allowance: Account<'info, Allowance>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> GrantAllowance<'info> {
+    pub fn grant_allowance(
+        &mut self,
+        amount_per_period: u64,
+        period: i64,
+        expires_at: i64,
+        bumps: GrantAllowanceBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(amount_per_period > 0, VaultError::InvalidAmount);
+        require!(period > 0 && expires_at > now, VaultError::InvalidAllowance);
+
+        self.allowance.set_inner(Allowance {
+            vault_state: self.vault_state.key(),
+            delegate: self.delegate.key(),
+            destination: self.destination.key(),
+            amount_per_period,
+            period,
+            expires_at,
+            period_start: now,
+            spent: 0,
+            bump: bumps.allowance,
+        });
+
+        self.vault_state.open_allowances = self.vault_state.open_allowances.checked_add(1).ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::VaultState;
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    pub user: Signer<'info>,
+    pub guardian: Option<Signer<'info>>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+}
+
+impl<'info> Heartbeat<'info> {
+    pub fn heartbeat(&mut self) -> Result<()> {
+        self.vault_state.last_heartbeat = Clock::get()?.unix_timestamp;
+
+        Ok(())
+    }
+
+    // Configuring the switch also counts as a heartbeat; `None` disables it
+    pub fn set_beneficiary(&mut self, beneficiary: Option<Pubkey>, inactivity_window: i64) -> Result<()> {
+        if beneficiary.is_some() {
+            require!(inactivity_window > 0, VaultError::InvalidInactivityWindow);
+
+            // A rate-limited vault must not be sweepable faster than a queued withdrawal, and a
+            // compromised owner key must not be able to redirect the sweep without the guardian
+            let state = &self.vault_state;
+            if state.withdraw_cap.is_some() {
+                require!(inactivity_window >= state.withdraw_delay, VaultError::InvalidInactivityWindow);
+
+                let loosening = beneficiary != state.beneficiary || inactivity_window < state.inactivity_window;
+                require!(!loosening || state.guardian_signed(self.guardian.as_ref()), VaultError::GuardianRequired);
+            }
+        }
+
+        self.vault_state.beneficiary = beneficiary;
+        self.vault_state.inactivity_window = inactivity_window;
+
+        self.heartbeat()
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::state::VaultState;
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account{
+        init,
+        payer = user,
+        seeds = [b"state", user.key().as_ref()],
+        bump,
+        space = 8 + VaultState::INIT_SPACE
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        seeds = [b"vault", vault_state.key().as_ref()],
+        bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Initialize<'info> {
+    pub fn initialize(&mut self, unlock_at: Option<i64>, bumps: InitializeBumps) -> Result<()> {
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault_state.to_account_info().data_len());
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.user.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_ctx, rent_exempt)?;
+
+        self.vault_state.vault_bump = bumps.vault;
+        self.vault_state.state_bump = bumps.vault_state;
+        self.vault_state.unlock_at = unlock_at;
+        self.vault_state.beneficiary = None;
+        self.vault_state.inactivity_window = 0;
+        self.vault_state.last_heartbeat = Clock::get()?.unix_timestamp;
+        self.vault_state.withdraw_cap = None;
+        self.vault_state.cap_window = 0;
+        self.vault_state.window_start = 0;
+        self.vault_state.withdrawn_in_window = 0;
+        self.vault_state.withdraw_delay = 0;
+        self.vault_state.guardian = None;
+        self.vault_state.pending_count = 0;
+        self.vault_state.queued_count = 0;
+        self.vault_state.open_allowances = 0;
+        self.vault_state.open_schedules = 0;
+        self.vault_state.open_token_balances = 0;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+
+use crate::error::VaultError;
+use crate::state::{MultisigState, MAX_OWNERS};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct InitializeMultisig<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account{
+        init,
+        payer = creator,
+        seeds = [b"multisig", creator.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + MultisigState::INIT_SPACE
+    }]
+    pub multisig_state: Account<'info, MultisigState>,
+    #[account{
+        mut,
+        seeds = [b"vault", multisig_state.key().as_ref()],
+        bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeMultisig<'info> {
+    pub fn initialize_multisig(
+        &mut self,
+        seed: u64,
+        owners: Vec<Pubkey>,
+        threshold: u8,
+        bumps: InitializeMultisigBumps,
+    ) -> Result<()> {
+        require!(!owners.is_empty() && owners.len() <= MAX_OWNERS, VaultError::InvalidOwners);
+        require!(
+            owners.iter().enumerate().all(|(i, owner)| !owners[..i].contains(owner)),
+            VaultError::InvalidOwners
+        );
+        require!(threshold > 0 && threshold as usize <= owners.len(), VaultError::InvalidThreshold);
+
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.creator.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_ctx, rent_exempt)?;
+
+        self.multisig_state.set_inner(MultisigState {
+            seed,
+            owners,
+            threshold,
+            proposal_count: 0,
+            vault_bump: bumps.vault,
+            state_bump: bumps.multisig_state,
+        });
+
+        Ok(())
+    }
+}
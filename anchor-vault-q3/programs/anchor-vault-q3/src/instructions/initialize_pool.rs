@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::state::PoolState;
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account{
+        init,
+        payer = creator,
+        seeds = [b"pool", seed.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + PoolState::INIT_SPACE
+    }]
+    pub pool_state: Account<'info, PoolState>,
+    #[account{
+        mut,
+        seeds = [b"vault", pool_state.key().as_ref()],
+        bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        init,
+        payer = creator,
+        seeds = [b"shares", pool_state.key().as_ref()],
+        bump,
+        mint::decimals = 9,
+        mint::authority = pool_state,
+        mint::token_program = token_program,
+    }]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializePool<'info> {
+    pub fn initialize_pool(&mut self, seed: u64, bumps: InitializePoolBumps) -> Result<()> {
+        // The rent-exempt reserve stays in the vault and is never counted as pool assets
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.creator.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_ctx, rent_exempt)?;
+
+        self.pool_state.set_inner(PoolState {
+            seed,
+            share_mint: self.share_mint.key(),
+            vault_bump: bumps.vault,
+            state_bump: bumps.pool_state,
+            mint_bump: bumps.share_mint,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+pub mod approve_withdrawal;
+pub mod cancel_pending_withdrawal;
+pub mod cancel_proposal;
+pub mod cancel_schedule;
+pub mod claim_inheritance;
+pub mod claim_inheritance_token;
+pub mod close;
+pub mod close_token;
+pub mod crank_payment;
+pub mod create_schedule;
+pub mod delegated_withdraw;
+pub mod deposit;
+pub mod deposit_multisig;
+pub mod deposit_pool;
+pub mod deposit_token;
+pub mod execute_pending_withdrawal;
+pub mod execute_withdrawal;
+pub mod extend_lock;
+pub mod grant_allowance;
+pub mod heartbeat;
+pub mod initialize;
+pub mod initialize_multisig;
+pub mod initialize_pool;
+pub mod propose_withdrawal;
+pub mod queue_withdrawal;
+pub mod redeem_pool;
+pub mod revoke_allowance;
+pub mod set_rate_limit;
+pub mod set_token_rate_limit;
+pub mod withdraw;
+pub mod withdraw_token;
+
+pub use approve_withdrawal::*;
+pub use cancel_pending_withdrawal::*;
+pub use cancel_proposal::*;
+pub use cancel_schedule::*;
+pub use claim_inheritance::*;
+pub use claim_inheritance_token::*;
+pub use close::*;
+pub use close_token::*;
+pub use crank_payment::*;
+pub use create_schedule::*;
+pub use delegated_withdraw::*;
+pub use deposit::*;
+pub use deposit_multisig::*;
+pub use deposit_pool::*;
+pub use deposit_token::*;
+pub use execute_pending_withdrawal::*;
+pub use execute_withdrawal::*;
+pub use extend_lock::*;
+pub use grant_allowance::*;
+pub use heartbeat::*;
+pub use initialize::*;
+pub use initialize_multisig::*;
+pub use initialize_pool::*;
+pub use propose_withdrawal::*;
+pub use queue_withdrawal::*;
+pub use redeem_pool::*;
+pub use revoke_allowance::*;
+pub use set_rate_limit::*;
+pub use set_token_rate_limit::*;
+pub use withdraw::*;
+pub use withdraw_token::*;
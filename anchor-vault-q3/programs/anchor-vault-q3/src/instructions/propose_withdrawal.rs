@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{MultisigState, WithdrawalProposal};
+
+#[derive(Accounts)]
+pub struct ProposeWithdrawal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub recipient: SystemAccount<'info>,
+    #[account{
+        mut,
+        constraint = multisig_state.is_owner(&proposer.key()) @ VaultError::NotAnOwner,
+    }]
+    pub multisig_state: Account<'info, MultisigState>,
+    #[account{
+        init,
+        payer = proposer,
+        seeds = [b"proposal", multisig_state.key().as_ref(), multisig_state.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + WithdrawalProposal::INIT_SPACE
+    }]
+    pub proposal: Account<'info, WithdrawalProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeWithdrawal<'info> {
+    pub fn propose_withdrawal(&mut self, amount: u64, bumps: ProposeWithdrawalBumps) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        let index = self.multisig_state.proposal_count;
+
+        // The proposer's approval is implied
+        self.proposal.set_inner(WithdrawalProposal {
+            multisig: self.multisig_state.key(),
+            index,
+            proposer: self.proposer.key(),
+            recipient: self.recipient.key(),
+            amount,
+            approvals: vec![self.proposer.key()],
+            bump: bumps.proposal,
+        });
+
+        self.multisig_state.proposal_count = index.checked_add(1).ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{PendingWithdrawal, VaultState};
+
+#[derive(Accounts)]
+pub struct QueueWithdrawal<'info> {
+    #[account{mut}]
+    pub user: Signer<'info>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        init,
+        payer = user,
+        seeds = [b"pending", vault_state.key().as_ref(), vault_state.pending_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + PendingWithdrawal::INIT_SPACE
+    }]
+    pub pending_withdrawal: Account<'info, PendingWithdrawal>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> QueueWithdrawal<'info> {
+    pub fn queue_withdrawal(&mut self, amount: u64, bumps: QueueWithdrawalBumps) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        let index = self.vault_state.pending_count;
+
+        self.pending_withdrawal.set_inner(PendingWithdrawal {
+            vault_state: self.vault_state.key(),
+            index,
+            amount,
+            executable_at: now.checked_add(self.vault_state.withdraw_delay).ok_or(VaultError::Overflow)?,
+            bump: bumps.pending_withdrawal,
+        });
+
+        self.vault_state.pending_count = index.checked_add(1).ok_or(VaultError::Overflow)?;
+        self.vault_state.queued_count = self.vault_state.queued_count.checked_add(1).ok_or(VaultError::Overflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Transfer, transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Burn, Mint, TokenAccount, TokenInterface, burn},
+};
+
+use crate::error::VaultError;
+use crate::state::PoolState;
+
+#[derive(Accounts)]
+pub struct RedeemPool<'info> {
+    #[account{mut}]
+    pub user: Signer<'info>,
+    #[account{
+        seeds = [b"pool", pool_state.seed.to_le_bytes().as_ref()],
+        bump = pool_state.state_bump,
+        has_one = share_mint,
+    }]
+    pub pool_state: Account<'info, PoolState>,
+    #[account{
+        mut,
+        seeds = [b"vault", pool_state.key().as_ref()],
+        bump = pool_state.vault_bump,
+    }]
+    pub vault: SystemAccount<'info>,
+    #[account{
+        mut,
+        seeds = [b"shares", pool_state.key().as_ref()],
+        bump = pool_state.mint_bump,
+    }]
+    pub share_mint: InterfaceAccount<'info, Mint>,
+    #[account{
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    }]
+    pub user_shares: InterfaceAccount<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RedeemPool<'info> {
+    pub fn redeem_pool(&mut self, shares: u64) -> Result<()> {
+        require!(shares > 0, VaultError::InvalidAmount);
+        require!(shares <= self.user_shares.amount, VaultError::InsufficientFunds);
+
+        let rent_exempt = Rent::get()?.minimum_balance(self.vault.data_len());
+        let pool_assets = self.vault.lamports().saturating_sub(rent_exempt);
+        let supply = self.share_mint.supply;
+
+        let amount = PoolState::assets_for(shares, pool_assets, supply)?;
+
+        require!(amount > 0, VaultError::InvalidAmount);
+
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = Burn {
+            mint: self.share_mint.to_account_info(),
+            from: self.user_shares.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        burn(cpi_ctx, shares)?;
+
+        let cpi_program = self.system_program.to_account_info();
+
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.user.to_account_info(),
+        };
+
+        let seeds = &[
+            b"vault".as_ref(),
+            self.pool_state.to_account_info().key.as_ref(),
+            &[self.pool_state.vault_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer(cpi_ctx, amount)?;
+
+        Ok(())
+    }
+}
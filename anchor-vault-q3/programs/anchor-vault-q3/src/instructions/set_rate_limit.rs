@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::VaultState;
+
+#[derive(Accounts)]
+pub struct SetRateLimit<'info> {
+    pub user: Signer<'info>,
+    pub guardian: Option<Signer<'info>>,
+    #[account{
+        mut,
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+}
+
+impl<'info> SetRateLimit<'info> {
+    // The owner alone may only tighten an active limit; anything looser needs the current guardian
+    pub fn set_rate_limit(
+        &mut self,
+        withdraw_cap: Option<u64>,
+        cap_window: i64,
+        withdraw_delay: i64,
+        guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        if withdraw_cap.is_some() {
+            require!(cap_window > 0 && withdraw_delay > 0, VaultError::InvalidRateLimit);
+        }
+
+        let state = &self.vault_state;
+        let loosening = match state.withdraw_cap {
+            None => false,
+            Some(current_cap) => {
+                !matches!(withdraw_cap, Some(cap) if cap <= current_cap)
+                    || cap_window < state.cap_window
+                    || withdraw_delay < state.withdraw_delay
+                    || (state.guardian.is_some() && guardian != state.guardian)
+            }
+        };
+
+        let guardian_signed = state.guardian_signed(self.guardian.as_ref());
+        require!(!loosening || guardian_signed, VaultError::GuardianRequired);
+
+        // Only a newly enabled limit or a guardian co-signed update starts a fresh window, otherwise
+        // the owner could reset the amount already withdrawn by re-submitting the current limit
+        if state.withdraw_cap.is_none() || guardian_signed {
+            self.vault_state.window_start = Clock::get()?.unix_timestamp;
+            self.vault_state.withdrawn_in_window = 0;
+        }
+
+        self.vault_state.withdraw_cap = withdraw_cap;
+        self.vault_state.cap_window = cap_window;
+        self.vault_state.withdraw_delay = withdraw_delay;
+        self.vault_state.guardian = guardian;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+use crate::state::{TokenBalance, VaultState};
+
+#[derive(Accounts)]
+pub struct SetTokenRateLimit<'info> {
+    pub user: Signer<'info>,
+    pub guardian: Option<Signer<'info>>,
+    #[account{
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        seeds = [b"balance", vault_state.key().as_ref(), token_balance.mint.as_ref()],
+        bump = token_balance.bump,
+    }]
+    pub token_balance: Account<'info, TokenBalance>,
+}
+
+impl<'info> SetTokenRateLimit<'info> {
+    // Caps withdrawals of one mint per vault `cap_window`, with the same guardian rules as `set_rate_limit`
+    pub fn set_token_rate_limit(&mut self, withdraw_cap: Option<u64>) -> Result<()> {
+        if withdraw_cap.is_some() {
+            require!(self.vault_state.cap_window > 0, VaultError::InvalidRateLimit);
+        }
+
+        let current = self.token_balance.withdraw_cap;
+        let loosening = current.is_some_and(|current_cap| !matches!(withdraw_cap, Some(cap) if cap <= current_cap));
+
+        let guardian_signed = self.vault_state.guardian_signed(self.guardian.as_ref());
+        require!(!loosening || guardian_signed, VaultError::GuardianRequired);
+
+        if current.is_none() || guardian_signed {
+            self.token_balance.window_start = Clock::get()?.unix_timestamp;
+            self.token_balance.withdrawn_in_window = 0;
+        }
+
+        self.token_balance.withdraw_cap = withdraw_cap;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+};
+
+use crate::error::VaultError;
+use crate::state::{TokenBalance, VaultState};
+
+#[derive(Accounts)]
+pub struct WithdrawToken<'info> {
+    #[account{mut}]
+    pub user: Signer<'info>,
+    #[account{
+        mint::token_program = token_program,
+    }]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account{
+        init_if_needed,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    }]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        seeds = [b"state", user.key().as_ref()],
+        bump = vault_state.state_bump,
+    }]
+    pub vault_state: Account<'info, VaultState>,
+    #[account{
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_state,
+        associated_token::token_program = token_program,
+    }]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account{
+        mut,
+        seeds = [b"balance", vault_state.key().as_ref(), mint.key().as_ref()],
+        bump = token_balance.bump,
+    }]
+    pub token_balance: Account<'info, TokenBalance>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawToken<'info> {
+    pub fn withdraw_token(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, VaultError::InvalidAmount);
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(!self.vault_state.is_locked(now), VaultError::VaultLocked);
+
+        self.token_balance.record_withdrawal(amount, self.vault_state.cap_window, now)?;
+
+        let remaining = self.token_balance.amount
+            .checked_sub(amount)
+            .ok_or(VaultError::InsufficientFunds)?;
+
+        let cpi_program = self.token_program.to_account_info();
+
+        let cpi_accounts = TransferChecked {
+            from: self.token_vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.user_ata.to_account_info(),
+            authority: self.vault_state.to_account_info(),
+        };
+
+        // The vault token account is owned by the VaultState PDA
+        let seeds = &[
+            b"state".as_ref(),
+            self.user.to_account_info().key.as_ref(),
+            &[self.vault_state.state_bump],
+        ];
+
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+
+        self.token_balance.amount = remaining;
+
+        Ok(())
+    }
+}
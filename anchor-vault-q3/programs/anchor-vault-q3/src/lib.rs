@@ -2,7 +2,12 @@
 #![allow(deprecated)]
 
 use anchor_lang::prelude::*;
-use anchor_lang::system_program::{Transfer, transfer};
+
+pub mod error;
+pub mod instructions;
+pub mod state;
+
+use instructions::*;
 
 declare_id!("J8BRYS7j32PyLFJ2uEenv6FC2yEVJdC8ZuVNi5kdu8EF");
 
@@ -10,8 +15,8 @@ declare_id!("J8BRYS7j32PyLFJ2uEenv6FC2yEVJdC8ZuVNi5kdu8EF");
 pub mod anchor_vault_q3 {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        ctx.accounts.initialize(ctx.bumps)?;
+    pub fn initialize(ctx: Context<Initialize>, unlock_at: Option<i64>) -> Result<()> {
+        ctx.accounts.initialize(unlock_at, ctx.bumps)?;
 
         Ok(())
     }
@@ -27,144 +32,189 @@ pub mod anchor_vault_q3 {
 
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
-    #[account{
-        init,
-        payer = user,
-        seeds = [b"state", user.key().as_ref()],
-        bump,
-        space = 8 + VaultState::INIT_SPACE
-    }]
-    pub vault_state: Account<'info, VaultState>,
-    #[account{
-        mut,
-        seeds = [b"vault", vault_state.key().as_ref()],
-        bump,
-    }]
-    pub vault: SystemAccount<'info>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn close(ctx: Context<Close>) -> Result<()> {
+        ctx.accounts.close()?;
 
-impl<'info> Initialize<'info> {
-    pub fn initialize(&mut self, bumps: InitializeBumps) -> Result<()> {
-        let rent_exempt = Rent::get()?.minimum_balance(self.vault_state.to_account_info().data_len());
+        Ok(())
+    }
 
-        let cpi_program = self.system_program.to_account_info();
+    pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_token(amount, ctx.bumps)?;
 
-        let cpi_accounts = Transfer {
-            from: self.user.to_account_info(),
-            to: self.vault.to_account_info(),
-        };
+        Ok(())
+    }
 
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    pub fn withdraw_token(ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_token(amount)?;
 
-        transfer(cpi_ctx, rent_exempt)?;
+        Ok(())
+    }
 
-        self.vault_state.vault_bump = bumps.vault;
-        self.vault_state.state_bump = bumps.vault_state;
+    pub fn close_token(ctx: Context<CloseToken>) -> Result<()> {
+        ctx.accounts.close_token()?;
 
-        Ok({})
+        Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Deposit<'info> {
-    #[account{mut}]
-    pub user: Signer<'info>,
-    #[account{
-        mut,
-        seeds = [b"vault", vault_state.key().as_ref()],
-        bump = vault_state.vault_bump,
-    }]
-    pub vault: SystemAccount<'info>,
-    #[account{
-        seeds = [b"state", user.key().as_ref()],
-        bump = vault_state.state_bump,
-    }]
-    pub vault_state: Account<'info, VaultState>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn extend_lock(ctx: Context<ExtendLock>, unlock_at: i64) -> Result<()> {
+        ctx.accounts.extend_lock(unlock_at)?;
 
-impl<'info> Deposit<'info> {
-    pub fn deposit(&mut self, amount: u64) -> Result<()> {
-        let cpi_program = self.system_program.to_account_info();
+        Ok(())
+    }
 
-        let cpi_accounts = Transfer {
-            from: self.user.to_account_info(),
-            to: self.vault.to_account_info(),
-        };
+    pub fn initialize_multisig(ctx: Context<InitializeMultisig>, seed: u64, owners: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        ctx.accounts.initialize_multisig(seed, owners, threshold, ctx.bumps)?;
 
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        Ok(())
+    }
 
-        transfer(cpi_ctx, amount)?;
+    pub fn deposit_multisig(ctx: Context<DepositMultisig>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_multisig(amount)?;
 
-        Ok({})
+        Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Withdraw<'info> {
-    #[account{mut}]
-    pub user: Signer<'info>,
-    #[account{
-        mut,
-        seeds = [b"vault", vault_state.key().as_ref()],
-        bump = vault_state.vault_bump,
-    }]
-    pub vault: SystemAccount<'info>,
-    #[account{
-        seeds = [b"state", user.key().as_ref()],
-        bump = vault_state.state_bump,
-    }]
-    pub vault_state: Account<'info, VaultState>,
-    pub system_program: Program<'info, System>,
-}
+    pub fn propose_withdrawal(ctx: Context<ProposeWithdrawal>, amount: u64) -> Result<()> {
+        ctx.accounts.propose_withdrawal(amount, ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+        ctx.accounts.approve_withdrawal()?;
+
+        Ok(())
+    }
 
-impl<'info> Withdraw<'info> {
-    pub fn withdraw(&mut self, amount: u64) -> Result<()> {
-        // Check that the withdraw leaves the vault with a rent-exempt balance
-        // Check the account has enough funds for the user to withdraw
-        
-        let cpi_program = self.system_program.to_account_info();
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+        ctx.accounts.execute_withdrawal()?;
 
-        let cpi_accounts = Transfer {
-            from: self.vault.to_account_info(),
-            to: self.user.to_account_info(),
-        };
+        Ok(())
+    }
 
-        let seeds = &[
-            b"vault".as_ref(),
-            self.vault_state.to_account_info().key.as_ref(),
-            &[self.vault_state.vault_bump],
-        ];
+    pub fn cancel_proposal(_ctx: Context<CancelProposal>) -> Result<()> {
+        Ok(())
+    }
 
-        let signer_seeds = &[&seeds[..]];
+    pub fn grant_allowance(ctx: Context<GrantAllowance>, amount_per_period: u64, period: i64, expires_at: i64) -> Result<()> {
+        ctx.accounts.grant_allowance(amount_per_period, period, expires_at, ctx.bumps)?;
 
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-    
-        transfer(cpi_ctx, amount)?;
+        Ok(())
+    }
+
+    pub fn revoke_allowance(ctx: Context<RevokeAllowance>) -> Result<()> {
+        ctx.accounts.revoke_allowance()?;
 
-        Ok({})
+        Ok(())
     }
-}
 
-// Implement a context to close the account
-// Tip: Look for a close constraint
-// Don't forget to manually close the vault account
-// Don't the withdraw and deposit context have the same accounts? Can't we just use the same context in different instructions?
+    pub fn delegated_withdraw(ctx: Context<DelegatedWithdraw>, amount: u64) -> Result<()> {
+        ctx.accounts.delegated_withdraw(amount)?;
 
-#[account]
-#[derive(InitSpace)] // This macro does not take into consideration the anchor discriminator size
-pub struct VaultState {
-    pub vault_bump: u8,
-    pub state_bump: u8,
-}
+        Ok(())
+    }
+
+    pub fn create_schedule(
+        ctx: Context<CreateSchedule>,
+        seed: u64,
+        amount: u64,
+        interval: i64,
+        count: u32,
+        start_at: i64,
+    ) -> Result<()> {
+        ctx.accounts.create_schedule(seed, amount, interval, count, start_at, ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn cancel_schedule(ctx: Context<CancelSchedule>) -> Result<()> {
+        ctx.accounts.cancel_schedule()?;
 
-// impl Space for VaultState {
-//     const INIT_SPACE: usize = 8 + 1 + 1;
-// }
\ No newline at end of file
+        Ok(())
+    }
+
+    pub fn crank_payment(ctx: Context<CrankPayment>) -> Result<()> {
+        ctx.accounts.crank_payment()?;
+
+        Ok(())
+    }
+
+    pub fn set_beneficiary(ctx: Context<Heartbeat>, beneficiary: Option<Pubkey>, inactivity_window: i64) -> Result<()> {
+        ctx.accounts.set_beneficiary(beneficiary, inactivity_window)?;
+
+        Ok(())
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        ctx.accounts.heartbeat()?;
+
+        Ok(())
+    }
+
+    pub fn claim_inheritance(ctx: Context<ClaimInheritance>) -> Result<()> {
+        ctx.accounts.claim_inheritance()?;
+
+        Ok(())
+    }
+
+    pub fn claim_inheritance_token(ctx: Context<ClaimInheritanceToken>) -> Result<()> {
+        ctx.accounts.claim_inheritance_token()?;
+
+        Ok(())
+    }
+
+    pub fn initialize_pool(ctx: Context<InitializePool>, seed: u64) -> Result<()> {
+        ctx.accounts.initialize_pool(seed, ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn deposit_pool(ctx: Context<DepositPool>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_pool(amount)?;
+
+        Ok(())
+    }
+
+    pub fn redeem_pool(ctx: Context<RedeemPool>, shares: u64) -> Result<()> {
+        ctx.accounts.redeem_pool(shares)?;
+
+        Ok(())
+    }
+
+    pub fn set_rate_limit(
+        ctx: Context<SetRateLimit>,
+        withdraw_cap: Option<u64>,
+        cap_window: i64,
+        withdraw_delay: i64,
+        guardian: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.set_rate_limit(withdraw_cap, cap_window, withdraw_delay, guardian)?;
+
+        Ok(())
+    }
+
+    pub fn set_token_rate_limit(ctx: Context<SetTokenRateLimit>, withdraw_cap: Option<u64>) -> Result<()> {
+        ctx.accounts.set_token_rate_limit(withdraw_cap)?;
+
+        Ok(())
+    }
+
+    pub fn queue_withdrawal(ctx: Context<QueueWithdrawal>, amount: u64) -> Result<()> {
+        ctx.accounts.queue_withdrawal(amount, ctx.bumps)?;
+
+        Ok(())
+    }
+
+    pub fn execute_pending_withdrawal(ctx: Context<ExecutePendingWithdrawal>) -> Result<()> {
+        ctx.accounts.execute_pending_withdrawal()?;
+
+        Ok(())
+    }
+
+    pub fn cancel_pending_withdrawal(ctx: Context<CancelPendingWithdrawal>) -> Result<()> {
+        ctx.accounts.cancel_pending_withdrawal()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+// Capped, periodically resetting spending power over a vault granted to a delegate
+#[account]
+#[derive(InitSpace)]
+pub struct Allowance {
+    pub vault_state: Pubkey,
+    pub delegate: Pubkey,
+    pub destination: Pubkey,
+    pub amount_per_period: u64,
+    pub period: i64, // Length of a spending period in seconds
+    pub expires_at: i64,
+    pub period_start: i64,
+    pub spent: u64, // Spent in the current period
+    pub bump: u8,
+}
+
+impl Allowance {
+    // Moves `period_start` forward to the period containing `now`, resetting `spent` if it changed
+    pub fn roll_period(&mut self, now: i64) -> Option<()> {
+        let elapsed = now.checked_sub(self.period_start)?;
+        if elapsed >= self.period {
+            let periods = elapsed.checked_div(self.period)?;
+            self.period_start = self.period_start.checked_add(periods.checked_mul(self.period)?)?;
+            self.spent = 0;
+        }
+        Some(())
+    }
+}
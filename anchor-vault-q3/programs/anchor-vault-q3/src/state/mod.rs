@@ -0,0 +1,17 @@
+pub mod allowance;
+pub mod multisig_state;
+pub mod pending_withdrawal;
+pub mod pool_state;
+pub mod schedule;
+pub mod token_balance;
+pub mod vault_state;
+pub mod withdrawal_proposal;
+
+pub use allowance::*;
+pub use multisig_state::*;
+pub use pending_withdrawal::*;
+pub use pool_state::*;
+pub use schedule::*;
+pub use token_balance::*;
+pub use vault_state::*;
+pub use withdrawal_proposal::*;
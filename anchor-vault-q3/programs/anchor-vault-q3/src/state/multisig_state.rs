@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_OWNERS: usize = 10;
+
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigState {
+    pub seed: u64,
+    #[max_len(MAX_OWNERS)]
+    pub owners: Vec<Pubkey>,
+    pub threshold: u8, // Approvals required to execute a withdrawal proposal
+    pub proposal_count: u64,
+    pub vault_bump: u8,
+    pub state_bump: u8,
+}
+
+impl MultisigState {
+    pub fn is_owner(&self, key: &Pubkey) -> bool {
+        self.owners.contains(key)
+    }
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+// Withdrawal above the vault's rate limit, executable once its delay has elapsed
+#[account]
+#[derive(InitSpace)]
+pub struct PendingWithdrawal {
+    pub vault_state: Pubkey,
+    pub index: u64,
+    pub amount: u64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+
+// Virtual shares and lamports priced into every deposit and redemption, so a donation to an
+// almost empty pool can't inflate the share price enough to round later deposits down
+pub const VIRTUAL_SHARES: u128 = 1_000_000;
+pub const VIRTUAL_ASSETS: u128 = 1_000_000;
+
+// Pooled vault: depositors hold share tokens redeemable for a pro-rata cut of the pool's lamports
+#[account]
+#[derive(InitSpace)]
+pub struct PoolState {
+    pub seed: u64,
+    pub share_mint: Pubkey,
+    pub vault_bump: u8,
+    pub state_bump: u8,
+    pub mint_bump: u8,
+}
+
+impl PoolState {
+    pub fn shares_for(amount: u64, pool_assets: u64, supply: u64) -> Result<u64> {
+        Self::convert(amount, supply as u128 + VIRTUAL_SHARES, pool_assets as u128 + VIRTUAL_ASSETS)
+    }
+
+    pub fn assets_for(shares: u64, pool_assets: u64, supply: u64) -> Result<u64> {
+        Self::convert(shares, pool_assets as u128 + VIRTUAL_ASSETS, supply as u128 + VIRTUAL_SHARES)
+    }
+
+    // `value * numerator / denominator`, rounded down in the pool's favour
+    fn convert(value: u64, numerator: u128, denominator: u128) -> Result<u64> {
+        u64::try_from(
+            (value as u128)
+                .checked_mul(numerator).ok_or(VaultError::Overflow)?
+                .checked_div(denominator).ok_or(VaultError::Overflow)?
+        ).map_err(|_| error!(VaultError::Overflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_mints_one_to_one() {
+        assert_eq!(PoolState::shares_for(1_000, 0, 0).unwrap(), 1_000);
+        assert_eq!(PoolState::assets_for(1_000, 1_000, 1_000).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn conversions_are_pro_rata() {
+        // Including the virtual offset, the pool holds 4M lamports for 2M shares
+        assert_eq!(PoolState::shares_for(2_000_000, 3_000_000, 1_000_000).unwrap(), 1_000_000);
+        assert_eq!(PoolState::assets_for(1_000_000, 3_000_000, 1_000_000).unwrap(), 2_000_000);
+    }
+
+    #[test]
+    fn conversions_round_down() {
+        // A pool priced at 1.5 lamports per share
+        assert_eq!(PoolState::shares_for(10, 2_000_000, 1_000_000).unwrap(), 6);
+        assert_eq!(PoolState::assets_for(1, 2_000_000, 1_000_000).unwrap(), 1);
+    }
+
+    #[test]
+    fn donation_to_empty_pool_does_not_steal_next_deposit() {
+        let sol = 1_000_000_000;
+
+        // Attacker mints a single share, then donates 1 SOL straight to the vault
+        let attacker_shares = PoolState::shares_for(1, 0, 0).unwrap();
+        assert_eq!(attacker_shares, 1);
+        let mut pool_assets = 1 + sol;
+        let mut supply = attacker_shares;
+
+        // Victim deposits 1 SOL and still receives shares
+        let victim_shares = PoolState::shares_for(sol, pool_assets, supply).unwrap();
+        assert!(victim_shares > 0);
+        pool_assets += sol;
+        supply += victim_shares;
+
+        // The victim gets back almost all of their deposit and the attacker loses the donation
+        let victim_assets = PoolState::assets_for(victim_shares, pool_assets, supply).unwrap();
+        let attacker_assets = PoolState::assets_for(attacker_shares, pool_assets, supply).unwrap();
+        assert!(victim_assets >= sol - sol / 1_000_000);
+        assert!(attacker_assets < sol / 100_000);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert!(PoolState::assets_for(u64::MAX, u64::MAX, 0).is_err());
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// Recurring payout from a vault, paid out by anyone cranking `crank_payment`
+#[account]
+#[derive(InitSpace)]
+pub struct Schedule {
+    pub seed: u64,
+    pub vault_state: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub interval: i64, // Seconds between payments
+    pub remaining: u32, // Payments left before the schedule closes
+    pub next_payment_at: i64,
+    pub bump: u8,
+}
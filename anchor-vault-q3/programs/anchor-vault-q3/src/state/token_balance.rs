@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::record_in_window;
+
+// Tracks how much of a given mint the user has deposited into the vault's token account
+#[account]
+#[derive(InitSpace)]
+pub struct TokenBalance {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub withdraw_cap: Option<u64>, // Token base units per vault `cap_window`
+    pub window_start: i64,
+    pub withdrawn_in_window: u64,
+    pub bump: u8,
+}
+
+impl TokenBalance {
+    pub fn record_withdrawal(&mut self, amount: u64, cap_window: i64, now: i64) -> Result<()> {
+        let Some(cap) = self.withdraw_cap else {
+            return Ok(());
+        };
+
+        record_in_window(cap, cap_window, &mut self.window_start, &mut self.withdrawn_in_window, amount, now)
+    }
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VaultError;
+
+#[account]
+#[derive(InitSpace)] // This macro does not take into consideration the anchor discriminator size
+pub struct VaultState {
+    pub vault_bump: u8,
+    pub state_bump: u8,
+    pub unlock_at: Option<i64>, // Savings lock: no withdrawals before this unix timestamp
+    pub beneficiary: Option<Pubkey>, // May claim the vault once the owner stops sending heartbeats
+    pub inactivity_window: i64,
+    pub last_heartbeat: i64,
+    pub withdraw_cap: Option<u64>, // Lamports per window; larger withdrawals must be queued. Tokens are capped per mint on `TokenBalance`
+    pub cap_window: i64,
+    pub window_start: i64,
+    pub withdrawn_in_window: u64,
+    pub withdraw_delay: i64, // Seconds a queued withdrawal waits before it can execute
+    pub guardian: Option<Pubkey>, // May cancel queued withdrawals and approve loosening the limit
+    pub pending_count: u64,
+    pub queued_count: u32, // Queued withdrawals not yet executed or cancelled; close waits for zero
+    pub open_allowances: u32, // Must be revoked before close, since allowance PDAs outlive a re-init
+    pub open_schedules: u32, // Likewise for schedules, which must be cancelled or paid out
+    pub open_token_balances: u32, // Token vaults (and their `TokenBalance`) that must be closed first
+}
+
+impl VaultState {
+    pub fn is_locked(&self, now: i64) -> bool {
+        self.unlock_at.is_some_and(|unlock_at| now < unlock_at)
+    }
+
+    pub fn is_inactive(&self, now: i64) -> bool {
+        self.last_heartbeat
+            .checked_add(self.inactivity_window)
+            .is_some_and(|deadline| now > deadline)
+    }
+
+    pub fn guardian_signed(&self, guardian: Option<&Signer>) -> bool {
+        self.guardian.is_some() && guardian.map(|g| g.key()) == self.guardian
+    }
+
+    // Counts `amount` against the current window's cap, starting a new window if the last one ended
+    pub fn record_withdrawal(&mut self, amount: u64, now: i64) -> Result<()> {
+        let Some(cap) = self.withdraw_cap else {
+            return Ok(());
+        };
+
+        record_in_window(cap, self.cap_window, &mut self.window_start, &mut self.withdrawn_in_window, amount, now)
+    }
+}
+
+// Shared by the lamport cap and the per-mint token caps
+pub fn record_in_window(
+    cap: u64,
+    cap_window: i64,
+    window_start: &mut i64,
+    withdrawn_in_window: &mut u64,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    let window_end = window_start.checked_add(cap_window).ok_or(VaultError::Overflow)?;
+    if now >= window_end {
+        *window_start = now;
+        *withdrawn_in_window = 0;
+    }
+
+    let withdrawn = withdrawn_in_window.checked_add(amount).ok_or(VaultError::Overflow)?;
+    require!(withdrawn <= cap, VaultError::WithdrawalCapExceeded);
+
+    *withdrawn_in_window = withdrawn;
+
+    Ok(())
+}
+
+// impl Space for VaultState {
+//     const INIT_SPACE: usize = 8 + 1 + 1;
+// }
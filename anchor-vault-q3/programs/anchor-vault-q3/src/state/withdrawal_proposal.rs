@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+use crate::state::MAX_OWNERS;
+
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalProposal {
+    pub multisig: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    #[max_len(MAX_OWNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub bump: u8,
+}
@@ -14,7 +14,7 @@ describe("anchor-vault-q3", () => {
 
   it("Is initialized!", async () => {
     // Add your test here.
-    const tx = await program.methods.initialize().accountsPartial({
+    const tx = await program.methods.initialize(null).accountsPartial({
       user: provider.publicKey,
       vaultState,
       vault,
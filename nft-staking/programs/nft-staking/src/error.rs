@@ -0,0 +1,35 @@
+use anchor_lang::error_code;
+
+#[error_code]
+pub enum StakeError {
+    #[msg("Maximum number of staked NFTs reached")]
+    MaxStakeReached,
+    #[msg("Freeze period has not passed")]
+    FreezePeriodNotPassed,
+    #[msg("NFT does not belong to the collection")]
+    InvalidCollection,
+    #[msg("NFT collection is not verified")]
+    CollectionNotVerified,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Arithmetic underflow")]
+    Underflow,
+    #[msg("Stake account does not belong to this user and config")]
+    InvalidStakeAccount,
+    #[msg("Rarity multiplier is not proven by the config merkle root")]
+    InvalidMultiplierProof,
+    #[msg("Asset is not a Core asset owned by the user")]
+    InvalidAsset,
+    #[msg("NFT is not a programmable NFT")]
+    InvalidTokenStandard,
+    #[msg("Lock tier is not configured")]
+    InvalidLockTier,
+    #[msg("Lock tier has not elapsed")]
+    LockTierNotElapsed,
+    #[msg("Too many lock tiers or a tier bonus below 10000 basis points")]
+    InvalidLockTiers,
+    #[msg("Staking is paused")]
+    StakingPaused,
+    #[msg("Emergency unstake is not enabled")]
+    NotInEmergency,
+}
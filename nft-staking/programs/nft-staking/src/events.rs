@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct StakeEvent {
+    pub user: Pubkey,
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub multiplier: u16,
+    pub lock_tier: u8,
+    pub locked_until: i64,
+    pub amount_staked: u8,
+}
+
+#[event]
+pub struct UnstakeEvent {
+    pub user: Pubkey,
+    pub config: Pubkey,
+    pub mint: Pubkey,
+    pub points: u32,
+    pub rewards: u64,
+    pub emergency: bool,
+    pub amount_staked: u8,
+}
+
+#[event]
+pub struct ClaimEvent {
+    pub user: Pubkey,
+    pub config: Pubkey,
+    pub amount: u64,
+    pub lifetime_points: u64,
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{
+        mint_to,
+        MintTo,
+        Mint,
+        Token,
+        TokenAccount
+    },
+};
+
+use crate::error::StakeError;
+use crate::events::ClaimEvent;
+use crate::state::{StakeAccount, StakeConfig, StakeStats, UserAccount};
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
+    #[account(
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"rewards", config.key().as_ref()],
+        bump = config.rewards_bump,
+    )]
+    pub rewards_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = user,
+    )]
+    pub rewards_ata: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> Claim<'info> {
+    // Credits per-second rewards for every stake account passed in `remaining_accounts`
+    pub fn accrue_rewards(&mut self, stake_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let config = self.config.key();
+
+        for info in stake_accounts {
+            require!(info.is_writable, StakeError::InvalidStakeAccount);
+
+            let mut stake_account: Account<StakeAccount> = Account::try_from(info)?;
+            require_keys_eq!(stake_account.owner, self.user.key(), StakeError::InvalidStakeAccount);
+
+            // Ensure the stake account belongs to this config
+            let expected = Pubkey::create_program_address(
+                &[b"stake", stake_account.mint.as_ref(), config.as_ref(), &[stake_account.bump]],
+                &crate::ID,
+            ).map_err(|_| StakeError::InvalidStakeAccount)?;
+            require_keys_eq!(expected, info.key(), StakeError::InvalidStakeAccount);
+
+            let rewards = stake_account
+                .accrue(now, self.config.rewards_per_second)
+                .ok_or(StakeError::Overflow)?;
+            self.user_account.unclaimed_rewards = self.user_account.unclaimed_rewards
+                .checked_add(rewards)
+                .ok_or(StakeError::Overflow)?;
+
+            stake_account.exit(&crate::ID)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn claim(&mut self) -> Result<()> {
+        // Each point is worth one whole rewards token, on top of the accrued per-second rewards
+        let amount = 10_u64
+            .checked_pow(self.rewards_mint.decimals as u32)
+            .and_then(|unit| unit.checked_mul(self.user_account.points as u64))
+            .and_then(|points| points.checked_add(self.user_account.unclaimed_rewards))
+            .ok_or(StakeError::Overflow)?;
+
+        // Setup signer seeds
+        let id = self.config.id.to_le_bytes();
+        let seeds = &[
+            b"config".as_ref(),
+            self.config.collection_mint.as_ref(),
+            id.as_ref(),
+            &[self.config.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Mint rewards to the user, signed by the config PDA
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = MintTo {
+            mint: self.rewards_mint.to_account_info(),
+            to: self.rewards_ata.to_account_info(),
+            authority: self.config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        mint_to(cpi_ctx, amount)?;
+
+        // Reset points and rewards now that they have been converted
+        self.user_account.points = 0;
+        self.user_account.unclaimed_rewards = 0;
+
+        // Update global stats
+        self.stats.total_rewards = self.stats.total_rewards
+            .checked_add(amount)
+            .ok_or(StakeError::Overflow)?;
+
+        emit!(ClaimEvent {
+            user: self.user.key(),
+            config: self.config.key(),
+            amount,
+            lifetime_points: self.user_account.lifetime_points,
+        });
+
+        Ok(())
+    }
+}
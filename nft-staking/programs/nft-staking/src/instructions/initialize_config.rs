@@ -1,23 +1,39 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token};
 
-use crate::state::StakeConfig;
+use crate::error::StakeError;
+use crate::mpl_core;
+use crate::state::{StakeConfig, StakeStats};
 
 
 #[derive(Accounts)]
+#[instruction(id: u64)]
 pub struct InitializeConfig<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
+    /// CHECK: either a Token Metadata collection mint or a Core collection, checked in `initialize_config`
+    pub collection_mint: UncheckedAccount<'info>,
+
     #[account(
         init,
         payer = admin,
-        seeds = [b"config"],
+        seeds = [b"config", collection_mint.key().as_ref(), id.to_le_bytes().as_ref()],
         bump,
         space = StakeConfig::INIT_SPACE + 8
     )]
     pub config: Account<'info, StakeConfig>,
 
+    // Shared by every config; created by the first one
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"stats"],
+        bump,
+        space = StakeStats::INIT_SPACE + 8,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
     #[account(
         init_if_needed,
         payer = admin,
@@ -33,15 +49,58 @@ pub struct InitializeConfig<'info> {
 }
 
 impl<'info> InitializeConfig<'info> {
-    pub fn initialize_config(&mut self, points_per_stake: u8, max_stake: u8, freeze_period: u32, bumps: &InitializeConfigBumps) -> Result<()> {
+    pub fn initialize_config(&mut self, id: u64, points_per_stake: u8, max_stake: u8, freeze_period: u32, rewards_per_second: u64, bumps: &InitializeConfigBumps) -> Result<()> {
+        // Ensure the collection is a legacy collection mint or a Core collection
+        let is_mint = self.collection_mint.owner == &Token::id()
+            && Mint::try_deserialize(&mut &self.collection_mint.try_borrow_data()?[..]).is_ok();
+        require!(is_mint || mpl_core::is_collection(&self.collection_mint), StakeError::InvalidCollection);
+
         self.config.set_inner(StakeConfig { 
+            id,
+            admin: self.admin.key(),
+            collection_mint: self.collection_mint.key(),
             points_per_stake, 
             max_stake, 
             freeze_period, 
+            rewards_per_second,
+            multiplier_root: [0; 32],
+            lock_tiers: Vec::new(),
+            paused: false,
+            emergency: false,
             rewards_bump: bumps.rewards_mint, 
             bump: bumps.config,
         });
 
+        // Totals start at zero when the stats account is first created and are kept across configs
+        self.stats.bump = bumps.stats;
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    // A new `rewards_per_second` also applies to rewards accrued but not yet claimed
+    pub fn update_config(&mut self, points_per_stake: u8, max_stake: u8, freeze_period: u32, rewards_per_second: u64, paused: bool, emergency: bool) -> Result<()> {
+        self.config.points_per_stake = points_per_stake;
+        self.config.max_stake = max_stake;
+        self.config.freeze_period = freeze_period;
+        self.config.rewards_per_second = rewards_per_second;
+        self.config.paused = paused;
+        self.config.emergency = emergency;
+
         Ok(())
     }
 }
\ No newline at end of file
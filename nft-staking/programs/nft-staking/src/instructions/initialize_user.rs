@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::UserAccount;
+use crate::state::{StakeConfig, UserAccount};
 
 
 #[derive(Accounts)]
@@ -8,10 +8,16 @@ pub struct InitializeUser<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+
     #[account(
         init,
         payer = user,
-        seeds = [b"user", user.key().as_ref()],
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
         bump,
         space = UserAccount::INIT_SPACE + 8,
     )]
@@ -25,7 +31,9 @@ impl<'info> InitializeUser<'info> {
 
         self.user_account.set_inner(UserAccount {
             points: 0,
+            unclaimed_rewards: 0,
             amount_staked: 0,
+            lifetime_points: 0,
             bump: bumps.user_account
         });
 
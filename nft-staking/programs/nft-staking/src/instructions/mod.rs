@@ -1,9 +1,23 @@
+pub mod claim;
 pub mod initialize_config;
 pub mod initialize_user;
+pub mod set_lock_tiers;
+pub mod set_multiplier_root;
 pub mod stake;
+pub mod stake_core;
+pub mod stake_pnft;
 pub mod unstake;
+pub mod unstake_core;
+pub mod unstake_pnft;
 
+pub use claim::*;
 pub use initialize_config::*;
 pub use initialize_user::*;
+pub use set_lock_tiers::*;
+pub use set_multiplier_root::*;
 pub use stake::*;
-pub use unstake::*;
\ No newline at end of file
+pub use stake_core::*;
+pub use stake_pnft::*;
+pub use unstake::*;
+pub use unstake_core::*;
+pub use unstake_pnft::*;
\ No newline at end of file
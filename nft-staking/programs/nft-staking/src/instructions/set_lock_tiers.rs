@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::error::StakeError;
+use crate::state::{LockTier, StakeConfig, BASE_MULTIPLIER, MAX_LOCK_TIERS};
+
+#[derive(Accounts)]
+pub struct SetLockTiers<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+}
+
+impl<'info> SetLockTiers<'info> {
+    // Only affects stakes made after the update; existing stakes keep their lock and bonus
+    pub fn set_lock_tiers(&mut self, lock_tiers: Vec<LockTier>) -> Result<()> {
+        require!(
+            lock_tiers.len() <= MAX_LOCK_TIERS && lock_tiers.iter().all(|t| t.bonus >= BASE_MULTIPLIER),
+            StakeError::InvalidLockTiers
+        );
+
+        self.config.lock_tiers = lock_tiers;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::StakeConfig;
+
+#[derive(Accounts)]
+pub struct SetMultiplierRoot<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = admin,
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+}
+
+impl<'info> SetMultiplierRoot<'info> {
+    // Only affects stakes made after the update; existing stakes keep their proven multiplier
+    pub fn set_multiplier_root(&mut self, multiplier_root: [u8; 32]) -> Result<()> {
+        self.config.multiplier_root = multiplier_root;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -18,7 +18,8 @@ use anchor_spl::{
     },
 };
 
-use crate::state::{StakeAccount, StakeConfig, UserAccount};
+use crate::error::StakeError;
+use crate::state::{NewStake, StakeAccount, StakeConfig, StakeStats, Staker, UserAccount};
 
 #[derive(Accounts)]
 pub struct Stake<'info> {
@@ -27,8 +28,6 @@ pub struct Stake<'info> {
     
     pub mint: Account<'info, Mint>,
 
-    pub collection_mint: Account<'info, Mint>,
-
     #[account(
         mut,
         associated_token::mint = mint,
@@ -40,8 +39,8 @@ pub struct Stake<'info> {
         seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref()],
         seeds::program = metadata_program.key(),
         bump,
-        constraint = metadata.collection.as_ref().unwrap().key.as_ref() == collection_mint.key().as_ref(),
-        constraint = metadata.collection.as_ref().unwrap().verified == true,
+        constraint = metadata.collection.as_ref().is_some_and(|c| c.key == config.collection_mint) @ StakeError::InvalidCollection,
+        constraint = metadata.collection.as_ref().is_some_and(|c| c.verified) @ StakeError::CollectionNotVerified,
     )]
     pub metadata: Account<'info, MetadataAccount>,
 
@@ -53,7 +52,7 @@ pub struct Stake<'info> {
     pub edition: Account<'info, MasterEditionAccount>,
 
     #[account(
-        seeds = [b"config"],
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
     )]
     pub config: Account<'info, StakeConfig>,
@@ -69,28 +68,50 @@ pub struct Stake<'info> {
 
     #[account(
         mut,
-        seeds = [b"user", user.key().as_ref()],
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
         bump = user_account.bump,
     )]
     pub user_account: Account<'info, UserAccount>,
 
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"staker", user.key().as_ref()],
+        bump,
+        space = Staker::INIT_SPACE + 8,
+    )]
+    pub staker: Account<'info, Staker>,
+
     pub metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
 }
 
 impl<'info> Stake<'info> {
-    pub fn stake(&mut self, bumps: &StakeBumps) -> Result<()> {
-        // Ensure max stake not reached
-        assert!(self.user_account.amount_staked < self.config.max_stake);
-
-        // Set fields of stake account
-        self.stake_account.set_inner(StakeAccount { 
-            owner: self.user.key(), 
-            mint: self.mint.key(), 
-            staked_at: Clock::get()?.unix_timestamp, 
-            bump: bumps.stake_account
-        });
+    pub fn stake(&mut self, multiplier: u16, proof: Vec<[u8; 32]>, lock_tier: u8, bumps: &StakeBumps) -> Result<()> {
+        // Validate, count and record the stake
+        self.user_account.open_stake(
+            NewStake {
+                owner: self.user.key(),
+                mint: self.mint.key(),
+                multiplier,
+                proof,
+                lock_tier,
+                stake_bump: bumps.stake_account,
+                staker_bump: bumps.staker,
+            },
+            &self.config,
+            &mut self.stats,
+            &mut self.staker,
+            &mut self.stake_account,
+        )?;
 
         // Delegate NFT to stake account
         let cpi_program = self.token_program.to_account_info();
@@ -128,9 +149,6 @@ impl<'info> Stake<'info> {
                 token_program 
             }
         ).invoke_signed(signer_seeds)?;
-        
-        // Update amount staked in user account
-        self.user_account.amount_staked += 1;
 
         Ok(())
     }
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::error::StakeError;
+use crate::mpl_core::{self, FreezeDelegateAccounts, MPL_CORE_ID};
+use crate::state::{NewStake, StakeAccount, StakeConfig, StakeStats, Staker, UserAccount};
+
+#[derive(Accounts)]
+pub struct StakeCore<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: parsed as a Core asset in the constraints
+    #[account(
+        mut,
+        constraint = mpl_core::asset_owner_and_collection(&asset).is_some_and(|(owner, _)| owner == user.key()) @ StakeError::InvalidAsset,
+        constraint = mpl_core::asset_owner_and_collection(&asset).is_some_and(|(_, c)| c == config.collection_mint) @ StakeError::InvalidCollection,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: address is the config collection and Core verifies the asset belongs to it
+    #[account(
+        mut,
+        address = config.collection_mint @ StakeError::InvalidCollection,
+        constraint = mpl_core::is_collection(&collection) @ StakeError::InvalidCollection,
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"stake", asset.key().as_ref(), config.key().as_ref()],
+        bump,
+        space = StakeAccount::INIT_SPACE + 8,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"staker", user.key().as_ref()],
+        bump,
+        space = Staker::INIT_SPACE + 8,
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// CHECK: address is the Core program id
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeCore<'info> {
+    pub fn stake_core(&mut self, multiplier: u16, proof: Vec<[u8; 32]>, lock_tier: u8, bumps: &StakeCoreBumps) -> Result<()> {
+        // Validate, count and record the stake
+        self.user_account.open_stake(
+            NewStake {
+                owner: self.user.key(),
+                mint: self.asset.key(),
+                multiplier,
+                proof,
+                lock_tier,
+                stake_bump: bumps.stake_account,
+                staker_bump: bumps.staker,
+            },
+            &self.config,
+            &mut self.stats,
+            &mut self.staker,
+            &mut self.stake_account,
+        )?;
+
+        // Freeze asset with the stake account as freeze delegate
+        FreezeDelegateAccounts {
+            asset: &self.asset.to_account_info(),
+            collection: &self.collection.to_account_info(),
+            payer: &self.user.to_account_info(),
+            authority: &self.user.to_account_info(),
+            system_program: &self.system_program.to_account_info(),
+            mpl_core_program: &self.mpl_core_program.to_account_info(),
+        }.add_frozen(&self.stake_account.key())?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
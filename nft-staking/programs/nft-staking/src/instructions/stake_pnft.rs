@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::{
+    metadata::{
+        mpl_token_metadata::{
+            instructions::{
+                DelegateStakingV1Cpi,
+                DelegateStakingV1CpiAccounts,
+                DelegateStakingV1InstructionArgs,
+                LockV1Cpi,
+                LockV1CpiAccounts,
+                LockV1InstructionArgs
+            },
+            types::TokenStandard,
+        },
+        MasterEditionAccount,
+        Metadata,
+        MetadataAccount,
+        TokenRecordAccount,
+    }, 
+    token::{
+        Mint, 
+        Token, 
+        TokenAccount
+    },
+};
+
+use crate::error::StakeError;
+use crate::state::{NewStake, StakeAccount, StakeConfig, StakeStats, Staker, UserAccount};
+
+#[derive(Accounts)]
+pub struct StakePnft<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    pub mint_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = metadata_program.key(),
+        bump,
+        constraint = metadata.token_standard == Some(TokenStandard::ProgrammableNonFungible) @ StakeError::InvalidTokenStandard,
+        constraint = metadata.collection.as_ref().is_some_and(|c| c.key == config.collection_mint) @ StakeError::InvalidCollection,
+        constraint = metadata.collection.as_ref().is_some_and(|c| c.verified) @ StakeError::CollectionNotVerified,
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub edition: Account<'info, MasterEditionAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref(), b"token_record", mint_ata.key().as_ref()],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub token_record: Account<'info, TokenRecordAccount>,
+
+    #[account(
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+
+    #[account(
+        init,
+        payer = user,
+        seeds = [b"stake", mint.key().as_ref(), config.key().as_ref()],
+        bump,
+        space = StakeAccount::INIT_SPACE + 8,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"staker", user.key().as_ref()],
+        bump,
+        space = Staker::INIT_SPACE + 8,
+    )]
+    pub staker: Account<'info, Staker>,
+
+    /// CHECK: address is the instructions sysvar id
+    #[account(address = sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+    /// CHECK: only required for pNFTs with a rule set, validated by Token Metadata
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: only required for pNFTs with a rule set, validated by Token Metadata
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    pub metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> StakePnft<'info> {
+    pub fn stake_pnft(&mut self, multiplier: u16, proof: Vec<[u8; 32]>, lock_tier: u8, bumps: &StakePnftBumps) -> Result<()> {
+        // Validate, count and record the stake
+        self.user_account.open_stake(
+            NewStake {
+                owner: self.user.key(),
+                mint: self.mint.key(),
+                multiplier,
+                proof,
+                lock_tier,
+                stake_bump: bumps.stake_account,
+                staker_bump: bumps.staker,
+            },
+            &self.config,
+            &mut self.stats,
+            &mut self.staker,
+            &mut self.stake_account,
+        )?;
+
+        let user = &self.user.to_account_info();
+        let stake_account = &self.stake_account.to_account_info();
+        let metadata = &self.metadata.to_account_info();
+        let edition = &self.edition.to_account_info();
+        let token_record = &self.token_record.to_account_info();
+        let mint = &self.mint.to_account_info();
+        let token = &self.mint_ata.to_account_info();
+        let system_program = &self.system_program.to_account_info();
+        let sysvar_instructions = &self.sysvar_instructions.to_account_info();
+        let token_program = &self.token_program.to_account_info();
+        let metadata_program = &self.metadata_program.to_account_info();
+        let authorization_rules_program = self.authorization_rules_program.as_ref().map(|a| a.to_account_info());
+        let authorization_rules = self.authorization_rules.as_ref().map(|a| a.to_account_info());
+
+        // Delegate pNFT to stake account as staking delegate
+        DelegateStakingV1Cpi::new(
+            metadata_program,
+            DelegateStakingV1CpiAccounts {
+                delegate_record: None,
+                delegate: stake_account,
+                metadata,
+                master_edition: Some(edition),
+                token_record: Some(token_record),
+                mint,
+                token,
+                authority: user,
+                payer: user,
+                system_program,
+                sysvar_instructions,
+                spl_token_program: Some(token_program),
+                authorization_rules_program: authorization_rules_program.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
+            },
+            DelegateStakingV1InstructionArgs { amount: 1, authorization_data: None }
+        ).invoke()?;
+
+        // Setup signer seeds
+        let seeds = &[
+            b"stake",
+            self.mint.to_account_info().key.as_ref(),
+            self.config.to_account_info().key.as_ref(),
+            &[self.stake_account.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Lock pNFT as staking delegate
+        LockV1Cpi::new(
+            metadata_program,
+            LockV1CpiAccounts {
+                authority: stake_account,
+                token_owner: Some(user),
+                token,
+                mint,
+                metadata,
+                edition: Some(edition),
+                token_record: Some(token_record),
+                payer: user,
+                system_program,
+                sysvar_instructions,
+                spl_token_program: Some(token_program),
+                authorization_rules_program: authorization_rules_program.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
+            },
+            LockV1InstructionArgs { authorization_data: None }
+        ).invoke_signed(signer_seeds)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
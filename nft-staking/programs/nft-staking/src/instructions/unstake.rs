@@ -17,7 +17,7 @@ use anchor_spl::{
     },
 };
 
-use crate::state::{StakeAccount, StakeConfig, UserAccount};
+use crate::state::{StakeAccount, StakeConfig, StakeStats, UserAccount};
 
 #[derive(Accounts)]
 pub struct Unstake<'info> {
@@ -26,8 +26,6 @@ pub struct Unstake<'info> {
     
     pub mint: Account<'info, Mint>,
 
-    pub collection_mint: Account<'info, Mint>,
-
     #[account(
         mut,
         associated_token::mint = mint,
@@ -43,7 +41,7 @@ pub struct Unstake<'info> {
     pub edition: Account<'info, MasterEditionAccount>,
 
     #[account(
-        seeds = [b"config"],
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
         bump = config.bump,
     )]
     pub config: Account<'info, StakeConfig>,
@@ -58,25 +56,38 @@ pub struct Unstake<'info> {
 
     #[account(
         mut,
-        seeds = [b"user", user.key().as_ref()],
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
         bump = user_account.bump,
     )]
     pub user_account: Account<'info, UserAccount>,
 
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
     pub metadata_program: Program<'info, Metadata>,
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
 }
 
 impl<'info> Unstake<'info> {
-    pub fn unstake(&mut self) -> Result<()> {
-        // Ensure staked time satisfies freeze period
-        let time_elapsed = ((Clock::get()?.unix_timestamp - self.stake_account.staked_at) / 86400) as u32;
-        assert!(time_elapsed >= self.config.freeze_period);
+    // Credits points and rewards for this stake, or forfeits them when `emergency` is set
+    pub fn unstake(&mut self, emergency: bool) -> Result<()> {
+        self.user_account.close_stake(
+            self.user.key(),
+            &mut self.stake_account,
+            &self.config,
+            &mut self.stats,
+            emergency,
+        )?;
 
-        // Update user points 
-        self.user_account.points += time_elapsed * (self.config.points_per_stake as u32);
+        self.release()
+    }
 
+    fn release(&mut self) -> Result<()> {
         // Setup signer seeds
         let seeds = &[
             b"stake",
@@ -112,9 +123,6 @@ impl<'info> Unstake<'info> {
         };
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         revoke(cpi_ctx)?;
-        
-        // Update amount staked in user account
-        self.user_account.amount_staked -= 1;
 
         Ok(())
     }
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::error::StakeError;
+use crate::mpl_core::{self, FreezeDelegateAccounts, MPL_CORE_ID};
+use crate::state::{StakeAccount, StakeConfig, StakeStats, UserAccount};
+
+#[derive(Accounts)]
+pub struct UnstakeCore<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: parsed as a Core asset in the constraints
+    #[account(
+        mut,
+        constraint = mpl_core::asset_owner_and_collection(&asset).is_some_and(|(owner, _)| owner == user.key()) @ StakeError::InvalidAsset,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    /// CHECK: address is the config collection and Core verifies the asset belongs to it
+    #[account(
+        mut,
+        address = config.collection_mint @ StakeError::InvalidCollection,
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"stake", asset.key().as_ref(), config.key().as_ref()],
+        bump = stake_account.bump,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
+    /// CHECK: address is the Core program id
+    #[account(address = MPL_CORE_ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UnstakeCore<'info> {
+    // Credits points and rewards for this stake, or forfeits them when `emergency` is set
+    pub fn unstake_core(&mut self, emergency: bool) -> Result<()> {
+        self.user_account.close_stake(
+            self.user.key(),
+            &mut self.stake_account,
+            &self.config,
+            &mut self.stats,
+            emergency,
+        )?;
+
+        self.release()
+    }
+
+    fn release(&mut self) -> Result<()> {
+        // Setup signer seeds
+        let seeds = &[
+            b"stake",
+            self.asset.to_account_info().key.as_ref(),
+            self.config.to_account_info().key.as_ref(),
+            &[self.stake_account.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Thaw asset and remove the freeze delegate plugin
+        let freeze_delegate = FreezeDelegateAccounts {
+            asset: &self.asset.to_account_info(),
+            collection: &self.collection.to_account_info(),
+            payer: &self.user.to_account_info(),
+            authority: &self.stake_account.to_account_info(),
+            system_program: &self.system_program.to_account_info(),
+            mpl_core_program: &self.mpl_core_program.to_account_info(),
+        };
+        freeze_delegate.thaw(signer_seeds)?;
+        freeze_delegate.remove(signer_seeds)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
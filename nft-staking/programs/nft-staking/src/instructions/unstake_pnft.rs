@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::{
+    metadata::{
+        mpl_token_metadata::{
+            instructions::{
+                RevokeStakingV1Cpi,
+                RevokeStakingV1CpiAccounts,
+                UnlockV1Cpi,
+                UnlockV1CpiAccounts,
+                UnlockV1InstructionArgs
+            },
+        },
+        MasterEditionAccount,
+        Metadata,
+        MetadataAccount,
+        TokenRecordAccount,
+    }, 
+    token::{
+        Mint, 
+        Token, 
+        TokenAccount
+    },
+};
+
+use crate::state::{StakeAccount, StakeConfig, StakeStats, UserAccount};
+
+#[derive(Accounts)]
+pub struct UnstakePnft<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    pub mint_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref()],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+
+    #[account(
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref(), b"edition"],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub edition: Account<'info, MasterEditionAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"metadata", metadata_program.key().as_ref(), mint.key().as_ref(), b"token_record", mint_ata.key().as_ref()],
+        seeds::program = metadata_program.key(),
+        bump,
+    )]
+    pub token_record: Account<'info, TokenRecordAccount>,
+
+    #[account(
+        seeds = [b"config", config.collection_mint.as_ref(), config.id.to_le_bytes().as_ref()],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, StakeConfig>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"stake", mint.key().as_ref(), config.key().as_ref()],
+        bump = stake_account.bump,
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"user", config.key().as_ref(), user.key().as_ref()],
+        bump = user_account.bump,
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = stats.bump,
+    )]
+    pub stats: Account<'info, StakeStats>,
+
+    /// CHECK: address is the instructions sysvar id
+    #[account(address = sysvar::instructions::ID)]
+    pub sysvar_instructions: UncheckedAccount<'info>,
+    /// CHECK: only required for pNFTs with a rule set, validated by Token Metadata
+    pub authorization_rules_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: only required for pNFTs with a rule set, validated by Token Metadata
+    pub authorization_rules: Option<UncheckedAccount<'info>>,
+
+    pub metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> UnstakePnft<'info> {
+    // Credits points and rewards for this stake, or forfeits them when `emergency` is set
+    pub fn unstake_pnft(&mut self, emergency: bool) -> Result<()> {
+        self.user_account.close_stake(
+            self.user.key(),
+            &mut self.stake_account,
+            &self.config,
+            &mut self.stats,
+            emergency,
+        )?;
+
+        self.release()
+    }
+
+    fn release(&mut self) -> Result<()> {
+        let user = &self.user.to_account_info();
+        let stake_account = &self.stake_account.to_account_info();
+        let metadata = &self.metadata.to_account_info();
+        let edition = &self.edition.to_account_info();
+        let token_record = &self.token_record.to_account_info();
+        let mint = &self.mint.to_account_info();
+        let token = &self.mint_ata.to_account_info();
+        let system_program = &self.system_program.to_account_info();
+        let sysvar_instructions = &self.sysvar_instructions.to_account_info();
+        let token_program = &self.token_program.to_account_info();
+        let metadata_program = &self.metadata_program.to_account_info();
+        let authorization_rules_program = self.authorization_rules_program.as_ref().map(|a| a.to_account_info());
+        let authorization_rules = self.authorization_rules.as_ref().map(|a| a.to_account_info());
+
+        // Setup signer seeds
+        let seeds = &[
+            b"stake",
+            self.mint.to_account_info().key.as_ref(),
+            self.config.to_account_info().key.as_ref(),
+            &[self.stake_account.bump]
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        // Unlock pNFT as staking delegate
+        UnlockV1Cpi::new(
+            metadata_program,
+            UnlockV1CpiAccounts {
+                authority: stake_account,
+                token_owner: Some(user),
+                token,
+                mint,
+                metadata,
+                edition: Some(edition),
+                token_record: Some(token_record),
+                payer: user,
+                system_program,
+                sysvar_instructions,
+                spl_token_program: Some(token_program),
+                authorization_rules_program: authorization_rules_program.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
+            },
+            UnlockV1InstructionArgs { authorization_data: None }
+        ).invoke_signed(signer_seeds)?;
+
+        // Revoke staking delegate from stake account
+        RevokeStakingV1Cpi::new(
+            metadata_program,
+            RevokeStakingV1CpiAccounts {
+                delegate_record: None,
+                delegate: stake_account,
+                metadata,
+                master_edition: Some(edition),
+                token_record: Some(token_record),
+                mint,
+                token,
+                authority: user,
+                payer: user,
+                system_program,
+                sysvar_instructions,
+                spl_token_program: Some(token_program),
+                authorization_rules_program: authorization_rules_program.as_ref(),
+                authorization_rules: authorization_rules.as_ref(),
+            }
+        ).invoke()?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
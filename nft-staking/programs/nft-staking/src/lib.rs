@@ -2,20 +2,80 @@
 
 use anchor_lang::prelude::*;
 
+mod error;
+mod events;
+mod mpl_core;
 mod state;
 mod instructions;
 
+use instructions::*;
+use state::LockTier;
+
 declare_id!("EEfTCAFozgqoeXVxGgwvVsLyR7fReqE3Z4UsP3JFQVg4");
 
 #[program]
 pub mod nft_staking {
     use super::*;
 
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        msg!("Greetings from: {:?}", ctx.program_id);
-        Ok(())
+    pub fn initialize_config(ctx: Context<InitializeConfig>, id: u64, points_per_stake: u8, max_stake: u8, freeze_period: u32, rewards_per_second: u64) -> Result<()> {
+        ctx.accounts.initialize_config(id, points_per_stake, max_stake, freeze_period, rewards_per_second, &ctx.bumps)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, points_per_stake: u8, max_stake: u8, freeze_period: u32, rewards_per_second: u64, paused: bool, emergency: bool) -> Result<()> {
+        ctx.accounts.update_config(points_per_stake, max_stake, freeze_period, rewards_per_second, paused, emergency)
+    }
+
+    pub fn initialize_user(ctx: Context<InitializeUser>) -> Result<()> {
+        ctx.accounts.initialize_user(&ctx.bumps)
+    }
+
+    pub fn set_lock_tiers(ctx: Context<SetLockTiers>, lock_tiers: Vec<LockTier>) -> Result<()> {
+        ctx.accounts.set_lock_tiers(lock_tiers)
+    }
+
+    pub fn set_multiplier_root(ctx: Context<SetMultiplierRoot>, multiplier_root: [u8; 32]) -> Result<()> {
+        ctx.accounts.set_multiplier_root(multiplier_root)
+    }
+
+    pub fn stake(ctx: Context<Stake>, multiplier: u16, proof: Vec<[u8; 32]>, lock_tier: u8) -> Result<()> {
+        ctx.accounts.stake(multiplier, proof, lock_tier, &ctx.bumps)
+    }
+
+    pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
+        ctx.accounts.unstake(false)
+    }
+
+    pub fn emergency_unstake(ctx: Context<Unstake>) -> Result<()> {
+        ctx.accounts.unstake(true)
+    }
+
+    pub fn stake_core(ctx: Context<StakeCore>, multiplier: u16, proof: Vec<[u8; 32]>, lock_tier: u8) -> Result<()> {
+        ctx.accounts.stake_core(multiplier, proof, lock_tier, &ctx.bumps)
+    }
+
+    pub fn unstake_core(ctx: Context<UnstakeCore>) -> Result<()> {
+        ctx.accounts.unstake_core(false)
+    }
+
+    pub fn emergency_unstake_core(ctx: Context<UnstakeCore>) -> Result<()> {
+        ctx.accounts.unstake_core(true)
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize {}
+    pub fn stake_pnft(ctx: Context<StakePnft>, multiplier: u16, proof: Vec<[u8; 32]>, lock_tier: u8) -> Result<()> {
+        ctx.accounts.stake_pnft(multiplier, proof, lock_tier, &ctx.bumps)
+    }
+
+    pub fn unstake_pnft(ctx: Context<UnstakePnft>) -> Result<()> {
+        ctx.accounts.unstake_pnft(false)
+    }
+
+    pub fn emergency_unstake_pnft(ctx: Context<UnstakePnft>) -> Result<()> {
+        ctx.accounts.unstake_pnft(true)
+    }
+
+    // Pass the user's stake accounts as writable remaining accounts to harvest without unstaking
+    pub fn claim<'info>(ctx: Context<'_, '_, 'info, 'info, Claim<'info>>) -> Result<()> {
+        ctx.accounts.accrue_rewards(ctx.remaining_accounts)?;
+        ctx.accounts.claim()
+    }
+}
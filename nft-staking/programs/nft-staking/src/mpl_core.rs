@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    pubkey,
+};
+
+// Minimal Metaplex Core bindings for the freeze-delegate plugin, built by hand like the prereqs client
+pub const MPL_CORE_ID: Pubkey = pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+// Core account `Key` discriminators
+const ASSET_V1: u8 = 1;
+const COLLECTION_V1: u8 = 5;
+
+// Core instruction discriminators
+const ADD_PLUGIN_V1: u8 = 2;
+const REMOVE_PLUGIN_V1: u8 = 4;
+const UPDATE_PLUGIN_V1: u8 = 6;
+
+// `Plugin::FreezeDelegate` / `PluginType::FreezeDelegate`
+const FREEZE_DELEGATE: u8 = 1;
+// `UpdateAuthority::Collection`
+const UPDATE_AUTHORITY_COLLECTION: u8 = 2;
+// `Some(PluginAuthority::Address { .. })`
+const SOME: u8 = 1;
+const AUTHORITY_ADDRESS: u8 = 3;
+
+// Returns the owner and collection of a Core asset that belongs to a collection
+pub fn asset_owner_and_collection(asset: &AccountInfo) -> Option<(Pubkey, Pubkey)> {
+    if asset.owner != &MPL_CORE_ID {
+        return None;
+    }
+
+    // Key (1) | owner (32) | update authority tag (1) | update authority address (32)
+    let data = asset.try_borrow_data().ok()?;
+    if data.len() < 66 || data[0] != ASSET_V1 || data[33] != UPDATE_AUTHORITY_COLLECTION {
+        return None;
+    }
+
+    let owner = Pubkey::try_from(&data[1..33]).ok()?;
+    let collection = Pubkey::try_from(&data[34..66]).ok()?;
+    Some((owner, collection))
+}
+
+pub fn is_collection(collection: &AccountInfo) -> bool {
+    collection.owner == &MPL_CORE_ID
+        && collection.try_borrow_data().is_ok_and(|data| data.first() == Some(&COLLECTION_V1))
+}
+
+pub struct FreezeDelegateAccounts<'a, 'info> {
+    pub asset: &'a AccountInfo<'info>,
+    pub collection: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub mpl_core_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> FreezeDelegateAccounts<'a, 'info> {
+    // Adds a frozen freeze-delegate plugin with `delegate` as its authority, signed by the asset owner
+    pub fn add_frozen(&self, delegate: &Pubkey) -> Result<()> {
+        let mut data = vec![ADD_PLUGIN_V1, FREEZE_DELEGATE, true as u8, SOME, AUTHORITY_ADDRESS];
+        data.extend_from_slice(delegate.as_ref());
+        self.invoke(data, &[])
+    }
+
+    // Thaws the asset, signed by the delegate
+    pub fn thaw(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.invoke(vec![UPDATE_PLUGIN_V1, FREEZE_DELEGATE, false as u8], signer_seeds)
+    }
+
+    // Removes the thawed plugin, signed by the delegate
+    pub fn remove(&self, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.invoke(vec![REMOVE_PLUGIN_V1, FREEZE_DELEGATE], signer_seeds)
+    }
+
+    fn invoke(&self, data: Vec<u8>, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let ix = Instruction {
+            program_id: MPL_CORE_ID,
+            accounts: vec![
+                AccountMeta::new(self.asset.key(), false),
+                AccountMeta::new(self.collection.key(), false),
+                AccountMeta::new(self.payer.key(), true),
+                AccountMeta::new_readonly(self.authority.key(), true),
+                AccountMeta::new_readonly(self.system_program.key(), false),
+                AccountMeta::new_readonly(MPL_CORE_ID, false), // no log wrapper
+            ],
+            data,
+        };
+
+        invoke_signed(
+            &ix,
+            &[
+                self.asset.clone(),
+                self.collection.clone(),
+                self.payer.clone(),
+                self.authority.clone(),
+                self.system_program.clone(),
+                self.mpl_core_program.clone(),
+            ],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,7 +1,9 @@
 pub mod stake_config;
 pub mod user_account;
 pub mod stake_account;
+pub mod stake_stats;
 
 pub use stake_config::*;
 pub use user_account::*;
 pub use stake_account::*;
+pub use stake_stats::*;
\ No newline at end of file
@@ -1,10 +1,47 @@
 use anchor_lang::prelude::*;
 
+// Multipliers are in basis points, so 10_000 earns the base rate
+pub const BASE_MULTIPLIER: u16 = 10_000;
+
+// Inputs shared by every stake path (legacy, Core and pNFT) for `UserAccount::open_stake`
+pub struct NewStake {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub multiplier: u16,
+    pub proof: Vec<[u8; 32]>,
+    pub lock_tier: u8,
+    pub stake_bump: u8,
+    pub staker_bump: u8,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct StakeAccount {
     pub owner: Pubkey,
     pub mint: Pubkey,
     pub staked_at: i64,
+    pub last_claimed_at: i64, // Rewards accrue per second from here
+    pub multiplier: u16, // Rarity multiplier in basis points, proven at stake time
+    pub lock_tier: u8,
+    pub locked_until: i64, // Unstake is rejected before this time
+    pub lock_bonus: u16, // Lock tier bonus in basis points, fixed at stake time
     pub bump: u8,
+}
+
+impl StakeAccount {
+    // Rewards accrued since the last claim; advances `last_claimed_at` to `now`
+    pub fn accrue(&mut self, now: i64, rewards_per_second: u64) -> Option<u64> {
+        let elapsed = u64::try_from(now.checked_sub(self.last_claimed_at)?).ok()?;
+        self.last_claimed_at = now;
+        self.apply_multiplier(elapsed.checked_mul(rewards_per_second)?)
+    }
+
+    // Scales a base amount by this NFT's rarity multiplier and lock tier bonus
+    pub fn apply_multiplier(&self, amount: u64) -> Option<u64> {
+        let scaled = (amount as u128)
+            .checked_mul(self.multiplier as u128)?
+            .checked_mul(self.lock_bonus as u128)?
+            / (BASE_MULTIPLIER as u128 * BASE_MULTIPLIER as u128);
+        u64::try_from(scaled).ok()
+    }
 }
\ No newline at end of file
@@ -1,11 +1,133 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::error::StakeError;
+use crate::state::BASE_MULTIPLIER;
+
+pub const MAX_LOCK_TIERS: usize = 4;
+
+// Optional lock chosen at stake time, boosting rewards in exchange for a longer lock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct LockTier {
+    pub days: u32,
+    pub bonus: u16, // Basis points, 10_000 means no bonus
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct StakeConfig {
+    pub id: u64,
+    pub admin: Pubkey,
+    pub collection_mint: Pubkey, // Only NFTs verified in this collection (mint or Core collection) can be staked
     pub points_per_stake: u8,
     pub max_stake: u8, // Max amount of NFTs to stake
     pub freeze_period: u32, // Period of time to lock the NFT,
+    pub rewards_per_second: u64, // Reward token base units emitted per staked NFT per second
+    pub multiplier_root: [u8; 32], // Merkle root of mint -> multiplier leaves, all zero when unset
+    #[max_len(MAX_LOCK_TIERS)]
+    pub lock_tiers: Vec<LockTier>, // Tier 0 is an unboosted no-lock tier when empty
+    pub paused: bool, // Blocks new stakes
+    pub emergency: bool, // Allows emergency unstakes that forfeit rewards
     pub rewards_bump: u8,
     pub bump: u8,
+}
+
+impl StakeConfig {
+    pub fn lock_tier(&self, tier: u8) -> Result<LockTier> {
+        if self.lock_tiers.is_empty() && tier == 0 {
+            return Ok(LockTier { days: 0, bonus: BASE_MULTIPLIER });
+        }
+
+        self.lock_tiers
+            .get(tier as usize)
+            .copied()
+            .ok_or(error!(StakeError::InvalidLockTier))
+    }
+
+    // Ensures the rarity multiplier is proven against the root, or is the base rate if no root is set
+    pub fn verify_multiplier(&self, mint: &Pubkey, multiplier: u16, proof: &[[u8; 32]]) -> Result<()> {
+        if self.multiplier_root == [0; 32] {
+            require_eq!(multiplier, BASE_MULTIPLIER, StakeError::InvalidMultiplierProof);
+            return Ok(());
+        }
+
+        // Leaves are sha256(mint || multiplier_le) and pairs are hashed in sorted order
+        let leaf = hashv(&[mint.as_ref(), &multiplier.to_le_bytes()]).to_bytes();
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            if node <= *sibling {
+                hashv(&[&node, sibling]).to_bytes()
+            } else {
+                hashv(&[sibling, &node]).to_bytes()
+            }
+        });
+
+        require!(root == self.multiplier_root, StakeError::InvalidMultiplierProof);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(multiplier_root: [u8; 32]) -> StakeConfig {
+        StakeConfig {
+            id: 0,
+            admin: Pubkey::default(),
+            collection_mint: Pubkey::default(),
+            points_per_stake: 0,
+            max_stake: 0,
+            freeze_period: 0,
+            rewards_per_second: 0,
+            multiplier_root,
+            lock_tiers: Vec::new(),
+            paused: false,
+            emergency: false,
+            rewards_bump: 0,
+            bump: 0,
+        }
+    }
+
+    fn leaf(mint: &Pubkey, multiplier: u16) -> [u8; 32] {
+        hashv(&[mint.as_ref(), &multiplier.to_le_bytes()]).to_bytes()
+    }
+
+    fn pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b { hashv(&[&a, &b]) } else { hashv(&[&b, &a]) }.to_bytes()
+    }
+
+    // Three-leaf tree: root = pair(pair(a, b), c)
+    fn tree() -> ([Pubkey; 3], [u8; 32], [[u8; 32]; 3]) {
+        let mints = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let leaves = [leaf(&mints[0], 15_000), leaf(&mints[1], 20_000), leaf(&mints[2], 10_000)];
+        let root = pair(pair(leaves[0], leaves[1]), leaves[2]);
+        (mints, root, leaves)
+    }
+
+    #[test]
+    fn unset_root_only_allows_the_base_multiplier() {
+        let config = config([0; 32]);
+        let mint = Pubkey::new_unique();
+        assert!(config.verify_multiplier(&mint, BASE_MULTIPLIER, &[]).is_ok());
+        assert!(config.verify_multiplier(&mint, 20_000, &[]).is_err());
+    }
+
+    #[test]
+    fn accepts_valid_proofs() {
+        let (mints, root, leaves) = tree();
+        let config = config(root);
+        assert!(config.verify_multiplier(&mints[0], 15_000, &[leaves[1], leaves[2]]).is_ok());
+        assert!(config.verify_multiplier(&mints[1], 20_000, &[leaves[0], leaves[2]]).is_ok());
+        assert!(config.verify_multiplier(&mints[2], 10_000, &[pair(leaves[0], leaves[1])]).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_claimed_multiplier_or_mint_not_in_the_tree() {
+        let (mints, root, leaves) = tree();
+        let config = config(root);
+        assert!(config.verify_multiplier(&mints[0], 20_000, &[leaves[1], leaves[2]]).is_err());
+        assert!(config.verify_multiplier(&Pubkey::new_unique(), 15_000, &[leaves[1], leaves[2]]).is_err());
+        assert!(config.verify_multiplier(&mints[0], 15_000, &[leaves[2]]).is_err());
+    }
 }
\ No newline at end of file
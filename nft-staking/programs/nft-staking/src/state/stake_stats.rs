@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+// Program-wide totals across every config, kept up to date by stake, unstake and claim for leaderboards
+#[account]
+#[derive(InitSpace)]
+pub struct StakeStats {
+    pub total_staked: u64,
+    pub total_points: u64, // Points credited to users over the program's lifetime
+    pub total_rewards: u64, // Reward token base units minted by `claim`
+    pub unique_stakers: u64,
+    pub bump: u8,
+}
+
+// One per wallet across every config, so `unique_stakers` counts each staker once
+#[account]
+#[derive(InitSpace)]
+pub struct Staker {
+    pub first_staked_at: i64, // Zero until the wallet's first stake
+    pub bump: u8,
+}
\ No newline at end of file
@@ -1,9 +1,186 @@
 use anchor_lang::prelude::*;
 
+use crate::error::StakeError;
+use crate::events::{StakeEvent, UnstakeEvent};
+use crate::state::{NewStake, StakeAccount, StakeConfig, StakeStats, Staker};
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserAccount {
     pub points: u32,
+    pub unclaimed_rewards: u64, // Accrued reward token base units awaiting `claim`
     pub amount_staked: u8,
+    pub lifetime_points: u64, // Never reset by `claim`
     pub bump: u8,
+}
+
+impl UserAccount {
+    // Validates and counts a new stake, then fills in its stake account and emits `StakeEvent`
+    pub fn open_stake(
+        &mut self,
+        stake: NewStake,
+        config: &Account<StakeConfig>,
+        stats: &mut StakeStats,
+        staker: &mut Staker,
+        stake_account: &mut Account<StakeAccount>,
+    ) -> Result<()> {
+        // Ensure max stake not reached and count the stake
+        let now = Clock::get()?.unix_timestamp;
+        self.record_stake(config, stats, staker, stake.staker_bump, now)?;
+
+        // Ensure the rarity multiplier is proven by the config
+        config.verify_multiplier(&stake.mint, stake.multiplier, &stake.proof)?;
+
+        // Ensure the lock tier is configured
+        let tier = config.lock_tier(stake.lock_tier)?;
+
+        // Set fields of stake account
+        let locked_until = (tier.days as i64)
+            .checked_mul(86400)
+            .and_then(|seconds| now.checked_add(seconds))
+            .ok_or(StakeError::Overflow)?;
+        stake_account.set_inner(StakeAccount { 
+            owner: stake.owner, 
+            mint: stake.mint, 
+            staked_at: now, 
+            last_claimed_at: now,
+            multiplier: stake.multiplier,
+            lock_tier: stake.lock_tier,
+            locked_until,
+            lock_bonus: tier.bonus,
+            bump: stake.stake_bump
+        });
+
+        emit!(StakeEvent {
+            user: stake.owner,
+            config: config.key(),
+            mint: stake.mint,
+            multiplier: stake.multiplier,
+            lock_tier: stake.lock_tier,
+            locked_until,
+            amount_staked: self.amount_staked,
+        });
+
+        Ok(())
+    }
+
+    // Counts a new stake against the config's per-user limit
+    fn record_stake(
+        &mut self,
+        config: &StakeConfig,
+        stats: &mut StakeStats,
+        staker: &mut Staker,
+        staker_bump: u8,
+        now: i64,
+    ) -> Result<()> {
+        // Ensure staking is not paused
+        require!(!config.paused, StakeError::StakingPaused);
+
+        // Ensure max stake not reached
+        require!(self.amount_staked < config.max_stake, StakeError::MaxStakeReached);
+
+        self.amount_staked = self.amount_staked
+            .checked_add(1)
+            .ok_or(StakeError::Overflow)?;
+
+        // Update global stats, counting the wallet once on its first stake in any config
+        if staker.first_staked_at == 0 {
+            staker.first_staked_at = now;
+            staker.bump = staker_bump;
+            stats.unique_stakers = stats.unique_stakers
+                .checked_add(1)
+                .ok_or(StakeError::Overflow)?;
+        }
+        stats.total_staked = stats.total_staked
+            .checked_add(1)
+            .ok_or(StakeError::Overflow)?;
+
+        Ok(())
+    }
+
+    // Settles a stake being closed and emits `UnstakeEvent`. In emergency mode the freeze period
+    // and lock tier are ignored and the stake's points and rewards are forfeited
+    pub fn close_stake(
+        &mut self,
+        owner: Pubkey,
+        stake_account: &mut StakeAccount,
+        config: &Account<StakeConfig>,
+        stats: &mut StakeStats,
+        emergency: bool,
+    ) -> Result<()> {
+        let (points, rewards) = if emergency {
+            require!(config.emergency, StakeError::NotInEmergency);
+            self.forfeit_stake(stats)?;
+            (0, 0)
+        } else {
+            self.settle_unstake(stake_account, config, stats, Clock::get()?.unix_timestamp)?
+        };
+
+        emit!(UnstakeEvent {
+            user: owner,
+            config: config.key(),
+            mint: stake_account.mint,
+            points,
+            rewards,
+            emergency,
+            amount_staked: self.amount_staked,
+        });
+
+        Ok(())
+    }
+
+    // Credits points and per-second rewards for a stake being closed, once its freeze period has passed.
+    // Returns the points and rewards credited
+    fn settle_unstake(&mut self, stake_account: &mut StakeAccount, config: &StakeConfig, stats: &mut StakeStats, now: i64) -> Result<(u32, u64)> {
+        // Ensure staked time satisfies freeze period
+        let seconds_staked = now
+            .checked_sub(stake_account.staked_at)
+            .ok_or(StakeError::Underflow)?;
+        let time_elapsed = u32::try_from(seconds_staked / 86400).map_err(|_| StakeError::Overflow)?;
+        require!(time_elapsed >= config.freeze_period, StakeError::FreezePeriodNotPassed);
+
+        // Ensure the chosen lock tier has elapsed
+        require!(now >= stake_account.locked_until, StakeError::LockTierNotElapsed);
+
+        // Settle rewards accrued since the last claim
+        let rewards = stake_account
+            .accrue(now, config.rewards_per_second)
+            .ok_or(StakeError::Overflow)?;
+        self.unclaimed_rewards = self.unclaimed_rewards
+            .checked_add(rewards)
+            .ok_or(StakeError::Overflow)?;
+
+        // Update user points 
+        let points = time_elapsed
+            .checked_mul(config.points_per_stake as u32)
+            .and_then(|points| stake_account.apply_multiplier(points as u64))
+            .and_then(|points| u32::try_from(points).ok())
+            .ok_or(StakeError::Overflow)?;
+        self.points = self.points
+            .checked_add(points)
+            .ok_or(StakeError::Overflow)?;
+        self.lifetime_points = self.lifetime_points
+            .checked_add(points as u64)
+            .ok_or(StakeError::Overflow)?;
+        stats.total_points = stats.total_points
+            .checked_add(points as u64)
+            .ok_or(StakeError::Overflow)?;
+
+        self.forfeit_stake(stats)?;
+
+        Ok((points, rewards))
+    }
+
+    // Removes a stake without crediting any points or rewards
+    fn forfeit_stake(&mut self, stats: &mut StakeStats) -> Result<()> {
+        // Update amount staked
+        self.amount_staked = self.amount_staked
+            .checked_sub(1)
+            .ok_or(StakeError::Underflow)?;
+        stats.total_staked = stats.total_staked
+            .checked_sub(1)
+            .ok_or(StakeError::Underflow)?;
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -8,9 +8,43 @@ describe("nft-staking", () => {
 
   const program = anchor.workspace.nftStaking as Program<NftStaking>;
 
+  // Collection whose verified NFTs may be staked in this pool
+  const collectionMint = anchor.web3.Keypair.generate();
+
+  before(async () => {
+    const provider = anchor.getProvider() as anchor.AnchorProvider;
+    const space = 82; // spl-token `Mint::LEN`
+    const lamports = await provider.connection.getMinimumBalanceForRentExemption(space);
+
+    // InitializeMint2: 0 decimals, the wallet as mint authority, no freeze authority
+    const data = Buffer.concat([
+      Buffer.from([20, 0]),
+      provider.wallet.publicKey.toBuffer(),
+      Buffer.alloc(33),
+    ]);
+
+    const tx = new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.createAccount({
+        fromPubkey: provider.wallet.publicKey,
+        newAccountPubkey: collectionMint.publicKey,
+        space,
+        lamports,
+        programId: anchor.utils.token.TOKEN_PROGRAM_ID,
+      }),
+      new anchor.web3.TransactionInstruction({
+        keys: [{ pubkey: collectionMint.publicKey, isSigner: false, isWritable: true }],
+        programId: anchor.utils.token.TOKEN_PROGRAM_ID,
+        data,
+      }),
+    );
+    await provider.sendAndConfirm(tx, [collectionMint]);
+  });
+
   it("Is initialized!", async () => {
     // Add your test here.
-    const tx = await program.methods.initialize().rpc();
+    const tx = await program.methods.initializeConfig(new anchor.BN(0), 10, 5, 0, new anchor.BN(1_000))
+      .accountsPartial({ collectionMint: collectionMint.publicKey })
+      .rpc();
     console.log("Your transaction signature", tx);
   });
 });